@@ -1,12 +1,14 @@
 use comemo::Track;
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 use typst_library::diag::SourceResult;
 use typst_library::engine::Engine;
 use typst_library::foundations::{Content, Context, Depth, Packed, StyleChain};
 use typst_library::introspection::Locator;
 use typst_library::layout::grid::resolve::{Cell, CellGrid};
 use typst_library::layout::{Axes, Fragment, HAlignment, Regions, Sizing, VAlignment};
-use typst_library::model::{EnumElem, ListElem, Numbering, ParElem, ParbreakElem};
+use typst_library::model::{
+    EnumElem, ListElem, Numbering, NumberingLocale, ParElem, ParbreakElem,
+};
 use typst_library::text::TextElem;
 
 use crate::grid::GridLayouter;
@@ -103,9 +105,10 @@ pub fn layout_enum(
             1
         }
     });
-    let mut parents = EnumElem::parents_in(styles);
+    let parents = EnumElem::parents_in(styles);
 
     let full = elem.full(styles);
+    let signed = |n: u64| i64::try_from(n).unwrap_or(i64::MAX);
 
     // Horizontally align based on the given respective parameter.
     // Vertically align to the top to avoid inheriting `horizon` or `bottom`
@@ -118,16 +121,23 @@ pub fn layout_enum(
 
         let context = Context::new(None, Some(styles));
         let resolved = if full {
-            parents.push(number);
-            let content = numbering.apply(engine, context.track(), &parents)?.display();
-            parents.pop();
-            content
+            let numbers: SmallVec<[i64; 4]> =
+                parents.iter().copied().chain([number]).map(signed).collect();
+            numbering
+                .apply(engine, context.track(), &numbers, elem.span())?
+                .display()
         } else {
             match numbering {
                 Numbering::Pattern(pattern) => {
-                    TextElem::packed(pattern.apply_kth(parents.len(), number))
+                    TextElem::packed(pattern.apply_kth_localized(
+                        parents.len(),
+                        signed(number),
+                        Some(NumberingLocale::new(styles)),
+                    ))
                 }
-                other => other.apply(engine, context.track(), &[number])?.display(),
+                other => other
+                    .apply(engine, context.track(), &[signed(number)], elem.span())?
+                    .display(),
             }
         };
 
@@ -200,7 +200,7 @@ pub fn layout_equation_block(
 
     let pod = Region::new(regions.base(), Axes::splat(false));
     let counter = Counter::of(EquationElem::elem())
-        .display_at_loc(engine, elem.location().unwrap(), styles, numbering)?
+        .display_at_loc(engine, elem.location().unwrap(), styles, numbering, span)?
         .spanned(span);
     let number = crate::layout_frame(engine, &counter, locator.next(&()), styles, pod)?;
 
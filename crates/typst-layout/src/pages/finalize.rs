@@ -23,6 +23,7 @@ pub fn finalize(
         foreground,
         fill,
         numbering,
+        locale,
         supplement,
     }: LayoutedPage,
 ) -> SourceResult<Page> {
@@ -70,5 +71,5 @@ pub fn finalize(
     let number = counter.logical();
     counter.step();
 
-    Ok(Page { frame, fill, numbering, supplement, number })
+    Ok(Page { frame, fill, numbering, locale, supplement, number })
 }
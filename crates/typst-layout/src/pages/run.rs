@@ -12,7 +12,7 @@ use typst_library::layout::{
     Length, OuterVAlignment, PageElem, Paper, Region, Regions, Rel, Sides, Size,
     VAlignment,
 };
-use typst_library::model::Numbering;
+use typst_library::model::{Numbering, NumberingLocale};
 use typst_library::routines::{Pair, Routines};
 use typst_library::text::{LocalName, TextElem};
 use typst_library::visualize::Paint;
@@ -36,6 +36,7 @@ pub struct LayoutedPage {
     pub foreground: Option<Frame>,
     pub fill: Smart<Option<Paint>>,
     pub numbering: Option<Numbering>,
+    pub locale: NumberingLocale,
     pub supplement: Content,
 }
 
@@ -142,15 +143,10 @@ fn layout_page_run_impl(
 
     // Construct the numbering (for header or footer).
     let numbering_marginal = numbering.as_ref().map(|numbering| {
-        let both = match numbering {
-            Numbering::Pattern(pattern) => pattern.pieces() >= 2,
-            Numbering::Func(_) => true,
-        };
-
         let mut counter = CounterDisplayElem::new(
             Counter::new(CounterKey::Page),
             Smart::Custom(numbering.clone()),
-            both,
+            displays_both(numbering),
         )
         .pack();
 
@@ -209,6 +205,7 @@ fn layout_page_run_impl(
             inner,
             fill: fill.clone(),
             numbering: numbering.clone(),
+            locale: NumberingLocale::new(styles),
             supplement: supplement.clone(),
             header: layout_marginal(header, header_size, Alignment::BOTTOM)?,
             footer: layout_marginal(footer, footer_size, Alignment::TOP)?,
@@ -271,3 +268,15 @@ fn determine_page_styles(children: &[Pair], initial: StyleChain) -> Styles {
         .map(|(_, style)| style)
         .collect()
 }
+
+/// Whether a page numbering displays the total number of pages next to the
+/// current one.
+fn displays_both(numbering: &Numbering) -> bool {
+    match numbering {
+        Numbering::Pattern(pattern) => pattern.pieces() >= 2,
+        Numbering::Func(_) => true,
+        Numbering::Alphabet(_) | Numbering::Symbols(_) => false,
+        Numbering::Map(map) => displays_both(map.numbering()),
+        Numbering::Reversed(reversed) => displays_both(reversed.numbering()),
+    }
+}
@@ -256,17 +256,20 @@ impl Counter {
     }
 
     /// Displays the value of the counter at the given location.
+    ///
+    /// Errors of the numbering are reported at the given span.
     pub fn display_at_loc(
         &self,
         engine: &mut Engine,
         loc: Location,
         styles: StyleChain,
         numbering: &Numbering,
+        span: Span,
     ) -> SourceResult<Content> {
         let context = Context::new(Some(loc), Some(styles));
         Ok(self
             .at_loc(engine, loc)?
-            .display(engine, context.track(), numbering)?
+            .display(engine, context.track(), numbering, span)?
             .display())
     }
 
@@ -361,6 +364,7 @@ impl Counter {
         numbering: Smart<Numbering>,
         both: bool,
         styles: Option<StyleChain>,
+        span: Span,
     ) -> SourceResult<Value> {
         let numbering = numbering
             .custom()
@@ -393,7 +397,7 @@ impl Counter {
         };
 
         let context = Context::new(Some(location), styles);
-        state.display(engine, context.track(), &numbering)
+        state.display(engine, context.track(), &numbering, span)
     }
 
     /// Selects all state updates.
@@ -464,7 +468,7 @@ impl Counter {
         both: bool,
     ) -> SourceResult<Value> {
         let loc = context.location().at(span)?;
-        self.display_impl(engine, loc, numbering, both, context.styles().ok())
+        self.display_impl(engine, loc, numbering, both, context.styles().ok(), span)
     }
 
     /// Retrieves the value of the counter at the given location. Always returns
@@ -616,13 +620,13 @@ pub trait Count {
 
 /// Counts through elements with different levels.
 #[derive(Debug, Clone, PartialEq, Hash)]
-pub struct CounterState(pub SmallVec<[u64; 3]>);
+pub struct CounterState(pub SmallVec<[i64; 3]>);
 
 impl CounterState {
     /// Get the initial counter state for the key.
     pub fn init(page: bool) -> Self {
         // Special case, because pages always start at one.
-        Self(smallvec![u64::from(page)])
+        Self(smallvec![i64::from(page)])
     }
 
     /// Advance the counter and return the numbers for the given heading.
@@ -652,12 +656,12 @@ impl CounterState {
             self.0.push(0);
         }
 
-        self.0[level - 1] = self.0[level - 1].saturating_add(by);
+        self.0[level - 1] = self.0[level - 1].saturating_add_unsigned(by);
         self.0.truncate(level);
     }
 
     /// Get the first number of the state.
-    pub fn first(&self) -> u64 {
+    pub fn first(&self) -> i64 {
         self.0.first().copied().unwrap_or(1)
     }
 
@@ -667,15 +671,16 @@ impl CounterState {
         engine: &mut Engine,
         context: Tracked<Context>,
         numbering: &Numbering,
+        span: Span,
     ) -> SourceResult<Value> {
-        numbering.apply(engine, context, &self.0)
+        numbering.apply(engine, context, &self.0, span)
     }
 }
 
 cast! {
     CounterState,
     self => Value::Array(self.0.into_iter().map(IntoValue::into_value).collect()),
-    num: u64 => Self(smallvec![num]),
+    num: i64 => Self(smallvec![num]),
     array: Array => Self(array
         .into_iter()
         .map(Value::cast)
@@ -748,6 +753,7 @@ impl Show for Packed<CounterDisplayElem> {
                 self.numbering.clone(),
                 self.both,
                 Some(styles),
+                self.span(),
             )?
             .display())
     }
@@ -758,7 +764,7 @@ impl Show for Packed<CounterDisplayElem> {
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ManualPageCounter {
     physical: NonZeroUsize,
-    logical: u64,
+    logical: i64,
 }
 
 impl ManualPageCounter {
@@ -773,7 +779,7 @@ impl ManualPageCounter {
     }
 
     /// Get the current logical page counter state.
-    pub fn logical(&self) -> u64 {
+    pub fn logical(&self) -> i64 {
         self.logical
     }
 
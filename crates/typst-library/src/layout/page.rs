@@ -15,7 +15,7 @@ use crate::layout::{
     Abs, Alignment, FlushElem, Frame, HAlignment, Length, OuterVAlignment, Ratio, Rel,
     Sides, SpecificAlignment,
 };
-use crate::model::{DocumentInfo, Numbering};
+use crate::model::{DocumentInfo, Numbering, NumberingLocale};
 use crate::text::LocalName;
 use crate::visualize::{Color, Paint};
 
@@ -478,11 +478,13 @@ pub struct Page {
     pub fill: Smart<Option<Paint>>,
     /// The page's numbering.
     pub numbering: Option<Numbering>,
+    /// The locale the page's numbering is applied in.
+    pub locale: NumberingLocale,
     /// The page's supplement.
     pub supplement: Content,
     /// The logical page number (controlled by `counter(page)` and may thus not
     /// match the physical number).
-    pub number: u64,
+    pub number: i64,
 }
 
 impl Page {
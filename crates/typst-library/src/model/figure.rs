@@ -606,7 +606,13 @@ impl Show for Packed<FigureCaption> {
             self.counter(),
             self.figure_location(),
         ) {
-            let numbers = counter.display_at_loc(engine, *location, styles, numbering)?;
+            let numbers = counter.display_at_loc(
+                engine,
+                *location,
+                styles,
+                numbering,
+                self.span(),
+            )?;
             if !supplement.is_empty() {
                 supplement += TextElem::packed('\u{a0}');
             }
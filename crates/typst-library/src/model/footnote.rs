@@ -143,7 +143,7 @@ impl Show for Packed<FootnoteElem> {
         let loc = self.declaration_location(engine).at(span)?;
         let numbering = self.numbering(styles);
         let counter = Counter::of(FootnoteElem::elem());
-        let num = counter.display_at_loc(engine, loc, styles, numbering)?;
+        let num = counter.display_at_loc(engine, loc, styles, numbering, span)?;
         let sup = SuperElem::new(num).pack().spanned(span);
         let loc = loc.variant(1);
         // Add zero-width weak spacing to make the footnote "sticky".
@@ -292,7 +292,7 @@ impl Show for Packed<FootnoteEntry> {
             );
         };
 
-        let num = counter.display_at_loc(engine, loc, styles, numbering)?;
+        let num = counter.display_at_loc(engine, loc, styles, numbering, span)?;
         let sup = SuperElem::new(num)
             .pack()
             .spanned(span)
@@ -234,7 +234,7 @@ impl Show for Packed<HeadingElem> {
         if let Some(numbering) = (**self).numbering(styles).as_ref() {
             let location = self.location().unwrap();
             let numbering = Counter::of(HeadingElem::elem())
-                .display_at_loc(engine, location, styles, numbering)?
+                .display_at_loc(engine, location, styles, numbering, span)?
                 .spanned(span);
 
             if hanging_indent.is_auto() && !html {
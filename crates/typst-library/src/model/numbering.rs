@@ -1,15 +1,29 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::num::NonZeroUsize;
+use std::ops::{Range, RangeInclusive};
 use std::str::FromStr;
+use std::sync::Arc;
 
 use chinese_number::{
     from_u64_to_chinese_ten_thousand as u64_to_chinese, ChineseCase, ChineseVariant,
 };
 use comemo::Tracked;
 use ecow::{eco_format, EcoString, EcoVec};
+use serde::de::Error as _;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use typst_syntax::{ast, Span, Spanned};
+use unicode_normalization::char::is_combining_mark;
 
-use crate::diag::SourceResult;
+use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
-use crate::foundations::{cast, func, Context, Func, Str, Value};
-use crate::text::Case;
+use crate::foundations::{
+    cast, func, ops, repr, scope, ty, Args, Cast, Context, Func, IntoValue, NoneValue,
+    OneOrMultiple, Repr, Str, StyleChain, Value,
+};
+use crate::text::{Case, Lang, Region, TextElem};
+use crate::World;
 
 /// Applies a numbering to a sequence of numbers.
 ///
@@ -51,20 +65,49 @@ use crate::text::Case;
 /// = Second heading
 /// = Third heading
 /// ```
-#[func]
+#[func(scope)]
 pub fn numbering(
     engine: &mut Engine,
     context: Tracked<Context>,
+    args: &mut Args,
+    span: Span,
     /// Defines how the numbering works.
     ///
     /// **Counting symbols** are `1`, `a`, `A`, `i`, `I`, `α`, `Α`, `一`, `壹`,
-    /// `あ`, `い`, `ア`, `イ`, `א`, `가`, `ㄱ`, `*`, `١`, `۱`, `१`, `১`, `ক`,
-    /// `①`, and `⓵`. They are replaced by the number in the sequence,
-    /// preserving the original case.
+    /// `あ`, `い`, `ア`, `イ`, `א`, `가`, `ㄱ`, `*`, `١`, `۱`, `१`, `১`, `ক`, `①`,
+    /// `⓵`, `ա`, `Ա`, `ა`, `፩`, `๑`, `ก`, `໑`, `១`, `၁`, `༡`, `௧`, `௰`, `౧`,
+    /// `೧`, `൧`, `૧`, `੧`, `୧`, `෧`, `᠑`, `߁`, `𞥑`, `а`, `А`, `أ`, `ا`, `آ`,
+    /// `壱`, `㋐`, `㊀`, `⑴`, `⒜`, `㈠`, `１`, `¹`, `₁`, `❶`, `➀`, `➊`, `⒈`, `𝍷`,
+    /// `𝍠`, `〡`, `𝋡`, `𝋁`, `Ⅰ`, `ⅰ`, `🕐`, `⚀`, `⠼`, `ㄅ`, `क`, `᭑`, `꧑`, `꩑`,
+    /// `꘡`, `𐒡`, `ⴰ`, `ܐ`, `𐋡`, `Ⰰ`, `𐄇`, and `ᚠ`. They are replaced by the
+    /// number in the sequence, preserving the original case. Some letters, like
+    /// `ա`, only count when they stand on their own, so that words in a prefix
+    /// like `{"Մաս ա."}` are kept as text.
+    ///
+    /// Numberings without a counting symbol of their own are written as
+    /// **named counting symbols** in braces: `{kanji}` (Japanese numerals),
+    /// `{greek-letter}` and `{Greek-letter}` (Greek letters instead of
+    /// numerals), `{hebrew-letter}`, `{cyrillic-numeral}`, `{1st}` (1st, 2nd,
+    /// 3rd), `{one}` and `{One}` (English number words), `{first}` and `{First}`
+    /// (English ordinal words), `{words}` (number words in the
+    /// [text language]($text.lang)), `{第一}` and `{第壹}` (Chinese ordinals),
+    /// `{hanja}` and `{hanja-formal}` (Korean Hanja numerals), and `{하나}`
+    /// (native Korean number words). Braces that don't enclose such a name,
+    /// like an unclosed `{`, are kept as text.
+    ///
+    /// The `⒈` character already includes a period, so it should not be
+    /// followed by a `.` suffix unless a doubled period is desired.
+    ///
+    /// Zeros in front of a decimal counting symbol pad the number to the width
+    /// of the whole run. For example, `{"001"}` turns 7 into "007" and leaves
+    /// 123 as is. The same works with the zero digit of the other decimal
+    /// systems, like `{"٠١"}`. Letters, numerals and symbols are never padded;
+    /// a `0` in front of them is just part of the prefix.
     ///
     /// The `*` character means that symbols should be used to count, in the
     /// order of `*`, `†`, `‡`, `§`, `¶`, `‖`. If there are more than six
-    /// items, the number is represented using repeated symbols.
+    /// items, the number is represented using repeated symbols. Beyond twenty
+    /// repetitions, the symbol is written once with its count, like `{"*×21"}`.
     ///
     /// **Suffixes** are all characters after the last counting symbol. They are
     /// repeated as-is at the end of any rendered number.
@@ -73,21 +116,511 @@ pub fn numbering(
     /// suffixes. They are repeated as-is at in front of their rendered
     /// equivalent of their counting symbol.
     ///
+    /// A counting symbol can also have a **suffix of its own**: the text after
+    /// it up to a `{|}`. It is added after the counting symbol if another one
+    /// follows or if it is the last one rendered, in which case it replaces the
+    /// pattern's suffix. For example, `{"1:{|}a."}` renders as "1:" for one
+    /// number and as "1:a." for two. A `|` on its own is just text.
+    ///
+    /// To use counting symbols as part of a prefix or suffix, quote the text
+    /// with `{'` and `'}`. For example, `{"{'Appendix'} A"}` renders as
+    /// "Appendix A" for the first number. Quoted text ends at the first `'}`.
+    ///
+    /// A pattern can be configured with the options below, like `trim`, by
+    /// passing them to the numbering function directly or by creating it with
+    /// [`numbering.pattern`]($numbering.pattern). The latter keeps the options
+    /// wherever the pattern is used, including in set rules. Passing options
+    /// along with a numbering that isn't a pattern is an error.
+    ///
     /// This parameter can also be an arbitrary function that gets each number
     /// as an individual argument. When given a function, the `numbering`
     /// function just forwards the arguments to that function. While this is not
     /// particularly useful in itself, it means that you can just give arbitrary
     /// numberings to the `numbering` function without caring whether they are
     /// defined as a pattern or function.
+    #[external]
     numbering: Numbering,
-    /// The numbers to apply the numbering to. Must be positive.
+    /// What to display for zero instead of the counting symbol's own
+    /// representation, which is `-` for most of them and the zero digit for
+    /// counting symbols that have one, like `1`.
+    ///
+    /// Applies to all counting symbols when set. Only applies if `numbering`
+    /// is a pattern.
+    ///
+    /// ```example
+    /// #numbering("a.1", 0, 0) \
+    /// #numbering("a.1", zero: "o", 0, 0) \
+    /// #numbering("1", zero: "–", 0)
+    /// ```
+    #[named]
+    zero: Option<Option<EcoString>>,
+    /// How to write Roman numerals of 4000 and above. Only applies if
+    /// `numbering` is a pattern.
+    ///
+    /// ```example
+    /// #numbering("I", 4000) \
+    /// #numbering("I", roman: "plain", 4000)
+    /// ```
+    #[named]
+    roman: Option<RomanStyle>,
+    /// Whether to mark Greek numerals with a keraia (ʹ). Without it, the
+    /// groups of myriads are separated by spaces instead of commas. Only
+    /// applies if `numbering` is a pattern.
+    ///
+    /// ```example
+    /// #numbering("α", 241) \
+    /// #numbering("α", keraia: false, 241)
+    /// ```
+    #[named]
+    keraia: Option<bool>,
+    /// Whether to leave out the first prefix and the suffix of a pattern, like
+    /// references do. Only applies if `numbering` is a pattern.
+    ///
+    /// With `{"prefix"}` or `{"suffix"}`, only the first prefix or only the
+    /// suffix is left out. The prefixes of the other counting symbols are
+    /// always kept.
+    ///
+    /// ```example
+    /// #numbering("(1.a)", 1, 2) \
+    /// #numbering("(1.a)", trim: true, 1, 2) \
+    /// #numbering("(1.a)", trim: "prefix", 1, 2) \
+    /// #numbering("(1.a)", trim: "suffix", 1, 2)
+    /// ```
+    #[named]
+    trim: Option<NumberingTrim>,
+    /// What to do if `numbering` is a pattern and more numbers than counting
+    /// symbols are given.
+    ///
+    /// ```example
+    /// #numbering("1.a", 1, 2, 3, 4) \
+    /// #numbering("1.a", overflow: "truncate", 1, 2, 3, 4)
+    /// ```
+    #[named]
+    overflow: Option<NumberingOverflow>,
+    /// What to do if `numbering` is a pattern and fewer numbers than counting
+    /// symbols are given.
+    ///
+    /// By default, the counting symbols without a number are left out
+    /// together with their prefixes. Given a string, it is displayed in place
+    /// of each missing number instead. With `{"error"}`, too few numbers are
+    /// an error.
+    ///
+    /// ```example
+    /// #numbering("1.1.1", 2) \
+    /// #numbering("1.1.1", underflow: "–", 2)
+    /// ```
+    #[named]
+    underflow: Option<NumberingUnderflow>,
+    /// How many numbers to display at most if `numbering` is a pattern.
+    ///
+    /// If more numbers are given, the first `max-depth - 1` ones and the last
+    /// one are displayed, with the `ellipsis` in place of the ones in between.
+    ///
+    /// ```example
+    /// #numbering("1.1", 1, 2, 3, 4, 5, 6) \
+    /// #numbering("1.1", max-depth: 3, 1, 2, 3, 4, 5, 6)
+    /// ```
+    #[named]
+    max_depth: Option<Option<NonZeroUsize>>,
+    /// What to display in place of the numbers elided because of `max-depth`.
+    #[named]
+    ellipsis: Option<EcoString>,
+    /// The numbers to apply the numbering to.
     ///
     /// If `numbering` is a pattern and more numbers than counting symbols are
-    /// given, the last counting symbol with its prefix is repeated.
+    /// given, the last counting symbol with its prefix is repeated by default.
+    /// This can be changed with the `overflow` parameter.
+    ///
+    /// Negative numbers are displayed with a minus sign in front of the
+    /// representation of their absolute value. This also applies to letters
+    /// and symbols, which have no negative counterparts of their own.
+    #[external]
     #[variadic]
-    numbers: Vec<u64>,
+    numbers: Vec<i64>,
 ) -> SourceResult<Value> {
-    numbering.apply(engine, context, &numbers)
+    let mut numbering = Numbering::from_spanned(engine, args.expect("numbering")?)?;
+    let numbers: Vec<i64> = args.all()?;
+    args.take().finish()?;
+    let options = NumberingOptions {
+        zero,
+        roman,
+        keraia,
+        trim,
+        overflow,
+        underflow,
+        max_depth,
+        ellipsis,
+    };
+    if let Numbering::Pattern(pattern) = &mut numbering {
+        options.apply(pattern);
+    } else if let Some(name) = options.first_given() {
+        bail!(span, "`{name}` only applies if the numbering is a pattern");
+    }
+    numbering.apply(engine, context, &numbers, span)
+}
+
+#[scope]
+impl numbering {
+    /// Creates a numbering pattern that keeps its options.
+    ///
+    /// Takes the same named arguments as the [`numbering`]($numbering)
+    /// function, like `trim` and `overflow`. Unlike there, they are kept
+    /// wherever the pattern is used, including in set rules. Without any
+    /// options, the pattern is just a string.
+    ///
+    /// ```example
+    /// #set heading(
+    ///   numbering: numbering.pattern("1.a", overflow: "truncate"),
+    /// )
+    ///
+    /// = Introduction
+    /// == Background
+    /// === Details
+    /// ```
+    #[func]
+    pub fn pattern(
+        /// The numbering pattern.
+        ///
+        /// The options below are applied on top of the options the pattern
+        /// already has.
+        pattern: NumberingPattern,
+        /// What to display for zero. See the [`numbering`]($numbering.zero)
+        /// function.
+        #[named]
+        zero: Option<Option<EcoString>>,
+        /// How to write large Roman numerals. See the
+        /// [`numbering`]($numbering.roman) function.
+        #[named]
+        roman: Option<RomanStyle>,
+        /// Whether to mark Greek numerals with a keraia. See the
+        /// [`numbering`]($numbering.keraia) function.
+        #[named]
+        keraia: Option<bool>,
+        /// Whether to leave out the first prefix and the suffix. See the
+        /// [`numbering`]($numbering.trim) function.
+        #[named]
+        trim: Option<NumberingTrim>,
+        /// What to do with more numbers than counting symbols. See the
+        /// [`numbering`]($numbering.overflow) function.
+        #[named]
+        overflow: Option<NumberingOverflow>,
+        /// What to do with fewer numbers than counting symbols. See the
+        /// [`numbering`]($numbering.underflow) function.
+        #[named]
+        underflow: Option<NumberingUnderflow>,
+        /// How many numbers to display at most. See the
+        /// [`numbering`]($numbering.max-depth) function.
+        #[named]
+        max_depth: Option<Option<NonZeroUsize>>,
+        /// What to display in place of the elided numbers. See the
+        /// [`numbering`]($numbering.ellipsis) function.
+        #[named]
+        ellipsis: Option<EcoString>,
+    ) -> NumberingPattern {
+        let options = NumberingOptions {
+            zero,
+            roman,
+            keraia,
+            trim,
+            overflow,
+            underflow,
+            max_depth,
+            ellipsis,
+        };
+        let mut pattern = pattern;
+        options.apply(&mut pattern);
+        pattern
+    }
+
+    /// Creates a numbering that counts with a custom alphabet.
+    ///
+    /// Like the letter counting symbols, the alphabet has no digit for zero:
+    /// After its last digit, counting continues with two digits, then three,
+    /// and so on. The digits may consist of multiple characters. When more
+    /// than one number is given, the results are separated by periods.
+    ///
+    /// The alphabet can be used wherever a numbering is expected.
+    ///
+    /// ```example
+    /// #let nato = numbering.alphabet(
+    ///   ("Alfa", "Bravo", "Charlie"),
+    /// )
+    /// #numbering(nato, 2) \
+    /// #numbering(nato, 5)
+    ///
+    /// #set heading(numbering: numbering.alphabet(("x", "y", "z")))
+    /// = Introduction
+    /// = Background
+    /// ```
+    #[func]
+    pub fn alphabet(
+        /// The digits of the alphabet, in counting order. There must be at
+        /// least two of them.
+        digits: Vec<EcoString>,
+        /// What to display for zero. If `{none}`, zero is displayed as `-`.
+        #[named]
+        zero: Option<EcoString>,
+    ) -> StrResult<NumberingAlphabet> {
+        if digits.len() < 2 {
+            bail!("alphabet must have at least two digits");
+        }
+        Ok(NumberingAlphabet { digits: digits.into(), zero })
+    }
+
+    /// Creates a numbering that counts with custom symbols.
+    ///
+    /// This works like the `*` counting symbol: The symbols are used in order
+    /// and once they are used up, they are repeated. The second cycle uses
+    /// each symbol twice, the third thrice, and so on. When more than one
+    /// number is given, the results are separated by periods.
+    ///
+    /// ```example
+    /// #let marks = numbering.symbols(("*", "†", "※"))
+    /// #for n in range(1, 7) [
+    ///   #numbering(marks, n)
+    /// ]
+    /// ```
+    #[func]
+    pub fn symbols(
+        /// The symbols, in counting order. There must be at least one.
+        symbols: Vec<EcoString>,
+    ) -> StrResult<NumberingSymbols> {
+        if symbols.is_empty() {
+            bail!("symbols must not be empty");
+        }
+        Ok(NumberingSymbols(symbols.into()))
+    }
+
+    /// Creates a numbering that transforms each number before displaying it
+    /// with another numbering.
+    ///
+    /// The number `n` is turned into `offset + (n - 1) * step`. With the
+    /// defaults, the numbers stay the same. The transformation is kept when
+    /// the numbering is used in a set rule or a counter.
+    ///
+    /// ```example
+    /// #let odd = numbering.map("1", step: 2)
+    /// #for n in range(1, 5) [#numbering(odd, n) ]
+    ///
+    /// #set heading(numbering: numbering.map("1.", offset: 5))
+    /// = Continued
+    /// ```
+    #[func]
+    pub fn map(
+        /// The numbering to display the transformed numbers with.
+        numbering: Numbering,
+        /// What the first number turns into.
+        #[named]
+        #[default(1)]
+        offset: i64,
+        /// How much each following number increases.
+        #[named]
+        #[default(1)]
+        step: i64,
+    ) -> NumberingMap {
+        NumberingMap { numbering: Arc::new(numbering), offset, step }
+    }
+
+    /// Creates a numbering that counts down from a total with another
+    /// numbering.
+    ///
+    /// The number `n` is turned into `total - n + 1`, so the first item
+    /// displays the total and the last one displays one. Numbers beyond the
+    /// total display zero.
+    ///
+    /// ```example
+    /// #let countdown = numbering.reversed("1", 3)
+    /// #for n in range(1, 5) [#numbering(countdown, n) ]
+    /// ```
+    #[func]
+    pub fn reversed(
+        /// The numbering to display the counted down numbers with.
+        numbering: Numbering,
+        /// The number to count down from.
+        total: u64,
+    ) -> NumberingReversed {
+        NumberingReversed { numbering: Arc::new(numbering), total }
+    }
+
+    /// Returns the number of counting symbols in a numbering pattern.
+    ///
+    /// ```example
+    /// #numbering.pieces("1.a)") \
+    /// #numbering.pieces("(I)")
+    /// ```
+    #[func]
+    pub fn pieces(
+        /// The numbering pattern.
+        pattern: NumberingPattern,
+    ) -> usize {
+        pattern.pieces()
+    }
+
+    /// Returns the prefix in front of a counting symbol of a numbering pattern.
+    ///
+    /// ```example
+    /// #numbering.prefix("{'Chapter '}1.a", 0) \
+    /// #numbering.prefix("{'Chapter '}1.a", 1)
+    /// ```
+    #[func]
+    pub fn prefix(
+        /// The numbering pattern.
+        pattern: NumberingPattern,
+        /// The index of the counting symbol, starting at zero.
+        index: usize,
+    ) -> StrResult<Str> {
+        let (prefix, _) = pattern.piece(index)?;
+        Ok(prefix.clone().into())
+    }
+
+    /// Returns the suffix after the last counting symbol of a numbering
+    /// pattern.
+    ///
+    /// This can, for instance, be used to check whether a heading numbering
+    /// ends with a period.
+    ///
+    /// ```example
+    /// #numbering.suffix("1.a)") \
+    /// #(numbering.suffix("1.") == ".")
+    /// ```
+    #[func]
+    pub fn suffix(
+        /// The numbering pattern.
+        pattern: NumberingPattern,
+    ) -> Str {
+        pattern.suffix.into()
+    }
+
+    /// Returns a counting symbol of a numbering pattern.
+    ///
+    /// Counting symbols without a character of their own are returned in
+    /// their named form, like `{"{kanji}"}`.
+    ///
+    /// ```example
+    /// #numbering.kind("1.a)", 1) \
+    /// #numbering.kind("{kanji}", 0)
+    /// ```
+    #[func]
+    pub fn kind(
+        /// The numbering pattern.
+        pattern: NumberingPattern,
+        /// The index of the counting symbol, starting at zero.
+        index: usize,
+    ) -> StrResult<Str> {
+        let (_, kind) = pattern.piece(index)?;
+        let mut symbol = EcoString::new();
+        write_symbol(*kind, 1, &mut symbol);
+        Ok(symbol.into())
+    }
+
+    /// Displays a range of numbers with a numbering, like "3–5" or "iii–v".
+    ///
+    /// If the range consists of just one number, it is displayed once. For
+    /// patterns, the earlier numbers of `from` are displayed once and only the
+    /// last one forms the range. For other numberings, both ends are displayed
+    /// in full.
+    ///
+    /// ```example
+    /// #numbering.range("1", 3, 5) \
+    /// #numbering.range("i", 3, 5) \
+    /// #numbering.range("1.1", (2, 3), 5) \
+    /// #numbering.range("1", 117, 119, collapse: true)
+    /// ```
+    #[func]
+    pub fn range(
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The numbering to display the range with.
+        numbering: Numbering,
+        /// The first number of the range. If this is an array, the numbers in
+        /// front of the last one are the numbers of the higher levels.
+        from: OneOrMultiple<i64>,
+        /// The last number of the range.
+        to: i64,
+        /// What to put between the start and the end of the range.
+        #[named]
+        #[default("–".into())]
+        separator: EcoString,
+        /// Whether to leave out the leading Arabic digits that the end shares
+        /// with the start, keeping at least two of them. Only applies if
+        /// `numbering` is a pattern.
+        #[named]
+        #[default(false)]
+        collapse: bool,
+    ) -> SourceResult<Value> {
+        let from = from.0;
+        let Some(&last) = from.last() else {
+            bail!(span, "range start must not be empty");
+        };
+
+        if let Numbering::Pattern(pattern) = &numbering {
+            pattern.check(from.len()).at(span)?;
+            let locale = context.styles().ok().map(NumberingLocale::new);
+            let range =
+                pattern.apply_range_localized(&from, to, &separator, collapse, locale);
+            return Ok(Value::Str(range.into()));
+        }
+
+        let start = numbering.apply(engine, context, &from, span)?;
+        if last == to {
+            return Ok(start);
+        }
+
+        let mut numbers = from;
+        *numbers.last_mut().unwrap() = to;
+        let end = numbering.apply(engine, context, &numbers, span)?;
+        let start = ops::join(start, separator.into_value()).at(span)?;
+        ops::join(start, end).at(span)
+    }
+
+    /// Recovers the numbers from text displayed with a numbering pattern.
+    ///
+    /// This is supported for Arabic and the other decimal systems, Roman
+    /// numerals up to 3999, Greek numerals up to 9999, Latin letters, and
+    /// Hebrew numerals. If the text can't be parsed or could be parsed in
+    /// multiple ways, returns `{none}`.
+    ///
+    /// ```example
+    /// #numbering.parse("I.1", "IV.2") \
+    /// #numbering.parse("A.1)", "B.3)") \
+    /// #numbering.parse("1", "IV")
+    /// ```
+    #[func]
+    pub fn parse(
+        /// The numbering pattern the text was displayed with.
+        pattern: NumberingPattern,
+        /// The displayed text.
+        text: Str,
+    ) -> Option<Vec<u64>> {
+        pattern.parse(&text)
+    }
+
+    /// Applies a single level of a numbering pattern to a number.
+    ///
+    /// The result consists of the first prefix, the counting symbol of the
+    /// given level, and the suffix. For levels beyond the pattern's counting
+    /// symbols, the last one is repeated, just like when applying the full
+    /// pattern to more numbers.
+    ///
+    /// ```example
+    /// #numbering.at("A.1)", 0, 2) \
+    /// #numbering.at("A.1)", 1, 2) \
+    /// #numbering.at("A.1)", 3, 2)
+    /// ```
+    #[func]
+    pub fn at(
+        context: Tracked<Context>,
+        /// The numbering pattern.
+        pattern: NumberingPattern,
+        /// The level whose counting symbol to use, starting at zero.
+        level: usize,
+        /// The number to apply it to.
+        number: i64,
+    ) -> Str {
+        let locale = context.styles().ok().map(NumberingLocale::new);
+        pattern.apply_kth_localized(level, number, locale).into()
+    }
 }
 
 /// How to number a sequence of things.
@@ -97,26 +630,72 @@ pub enum Numbering {
     Pattern(NumberingPattern),
     /// A closure mapping from an item's number to content.
     Func(Func),
+    /// A custom alphabet to count with.
+    Alphabet(NumberingAlphabet),
+    /// Custom symbols to count with.
+    Symbols(NumberingSymbols),
+    /// Another numbering applied to transformed numbers.
+    Map(NumberingMap),
+    /// Another numbering counting down from a total.
+    Reversed(NumberingReversed),
 }
 
 impl Numbering {
     /// Apply the pattern to the given numbers.
+    ///
+    /// Errors of patterns that reject the amount of numbers are reported at
+    /// the given span.
     pub fn apply(
         &self,
         engine: &mut Engine,
         context: Tracked<Context>,
-        numbers: &[u64],
+        numbers: &[i64],
+        span: Span,
     ) -> SourceResult<Value> {
         Ok(match self {
-            Self::Pattern(pattern) => Value::Str(pattern.apply(numbers).into()),
+            Self::Pattern(pattern) => {
+                pattern.check(numbers.len()).at(span)?;
+                let locale = context.styles().ok().map(NumberingLocale::new);
+                Value::Str(pattern.apply_localized(numbers, locale).into())
+            }
             Self::Func(func) => func.call(engine, context, numbers.iter().copied())?,
+            Self::Alphabet(alphabet) => Value::Str(alphabet.apply(numbers).into()),
+            Self::Symbols(symbols) => Value::Str(symbols.apply(numbers).into()),
+            Self::Map(map) => {
+                let numbers: Vec<_> = numbers.iter().map(|&n| map.map(n)).collect();
+                map.numbering.apply(engine, context, &numbers, span)?
+            }
+            Self::Reversed(reversed) => {
+                let numbers: Vec<_> =
+                    numbers.iter().map(|&n| reversed.reverse(n)).collect();
+                reversed.numbering.apply(engine, context, &numbers, span)?
+            }
         })
     }
 
+    /// Cast a value into a numbering. Errors in a pattern point to the
+    /// offending part of its string literal where possible.
+    fn from_spanned(engine: &Engine, value: Spanned<Value>) -> SourceResult<Self> {
+        match value.v {
+            Value::Str(text) => {
+                NumberingPattern::parse_spanned(engine, &text, value.span)
+                    .map(Self::Pattern)
+            }
+            v => v.cast().at(value.span),
+        }
+    }
+
     /// Trim the prefix suffix if this is a pattern.
-    pub fn trimmed(mut self) -> Self {
+    pub fn trimmed(self) -> Self {
+        self.with_trim(NumberingTrim { prefix: true, suffix: true })
+    }
+
+    /// Trim the first prefix and the suffix as configured if this is a
+    /// pattern.
+    pub fn with_trim(mut self, trim: NumberingTrim) -> Self {
         if let Self::Pattern(pattern) = &mut self {
-            pattern.trimmed = true;
+            pattern.trim_prefix |= trim.prefix;
+            pattern.trim_suffix |= trim.suffix;
         }
         self
     }
@@ -133,9 +712,206 @@ cast! {
     self => match self {
         Self::Pattern(pattern) => pattern.into_value(),
         Self::Func(func) => func.into_value(),
+        Self::Alphabet(alphabet) => alphabet.into_value(),
+        Self::Symbols(symbols) => symbols.into_value(),
+        Self::Map(map) => map.into_value(),
+        Self::Reversed(reversed) => reversed.into_value(),
     },
     v: NumberingPattern => Self::Pattern(v),
     v: Func => Self::Func(v),
+    v: NumberingAlphabet => Self::Alphabet(v),
+    v: NumberingSymbols => Self::Symbols(v),
+    v: NumberingMap => Self::Map(v),
+    v: NumberingReversed => Self::Reversed(v),
+}
+
+impl Serialize for Numbering {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Pattern(pattern) => pattern.serialize(serializer),
+            // Other numberings can't be represented as data, so we describe
+            // them by their type and repr.
+            other => {
+                let value = other.clone().into_value();
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("type", value.ty().short_name())?;
+                map.serialize_entry("repr", &value.repr())?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// A custom alphabet to count with.
+///
+/// Created with [`numbering.alphabet`]($numbering.alphabet).
+#[ty(cast, name = "alphabet")]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NumberingAlphabet {
+    /// The digits, in counting order. There are at least two of them.
+    digits: EcoVec<EcoString>,
+    /// What to display for zero instead of `-`.
+    zero: Option<EcoString>,
+}
+
+impl NumberingAlphabet {
+    /// Apply the alphabet to the given numbers, separating them by periods.
+    pub fn apply(&self, numbers: &[i64]) -> EcoString {
+        let mut fmt = EcoString::new();
+        for (i, &n) in numbers.iter().enumerate() {
+            if i > 0 {
+                fmt.push('.');
+            }
+            if n < 0 {
+                fmt.push('\u{2212}');
+            }
+            fmt.push_str(&self.apply_one(n.unsigned_abs()));
+        }
+        fmt
+    }
+
+    /// Stringify a single number without a zero digit.
+    fn apply_one(&self, mut n: u64) -> EcoString {
+        if n == 0 {
+            return self.zero.clone().unwrap_or_else(|| '-'.into());
+        }
+
+        let n_digits = self.digits.len() as u64;
+        let mut digits = Vec::new();
+        while n > 0 {
+            n -= 1;
+            digits.push(&self.digits[(n % n_digits) as usize]);
+            n /= n_digits;
+        }
+
+        let mut fmt = EcoString::new();
+        for digit in digits.into_iter().rev() {
+            fmt.push_str(digit);
+        }
+        fmt
+    }
+}
+
+impl Repr for NumberingAlphabet {
+    fn repr(&self) -> EcoString {
+        let digits: Vec<_> = self.digits.iter().map(Repr::repr).collect();
+        let mut parts = vec![EcoString::from(repr::pretty_array_like(&digits, false))];
+        if let Some(zero) = &self.zero {
+            parts.push(eco_format!("zero: {}", zero.repr()));
+        }
+        eco_format!("numbering.alphabet{}", repr::pretty_array_like(&parts, false))
+    }
+}
+
+/// Custom symbols to count with.
+///
+/// Created with [`numbering.symbols`]($numbering.symbols).
+#[ty(cast, name = "symbols")]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NumberingSymbols(EcoVec<EcoString>);
+
+impl NumberingSymbols {
+    /// Apply the symbols to the given numbers, separating them by periods.
+    pub fn apply(&self, numbers: &[i64]) -> EcoString {
+        let mut fmt = EcoString::new();
+        for (i, &n) in numbers.iter().enumerate() {
+            if i > 0 {
+                fmt.push('.');
+            }
+            if n < 0 {
+                fmt.push('\u{2212}');
+            }
+            fmt.push_str(&symbols(&self.0, n.unsigned_abs()));
+        }
+        fmt
+    }
+}
+
+impl Repr for NumberingSymbols {
+    fn repr(&self) -> EcoString {
+        let symbols: Vec<_> = self.0.iter().map(Repr::repr).collect();
+        let array = repr::pretty_array_like(&symbols, symbols.len() == 1);
+        eco_format!("numbering.symbols({array})")
+    }
+}
+
+/// A numbering that transforms numbers before displaying them.
+///
+/// Created with [`numbering.map`]($numbering.map).
+#[ty(cast, name = "mapped-numbering")]
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct NumberingMap {
+    /// The numbering to display the transformed numbers with.
+    numbering: Arc<Numbering>,
+    /// What the first number turns into.
+    offset: i64,
+    /// How much each following number increases.
+    step: i64,
+}
+
+impl NumberingMap {
+    /// The numbering the transformed numbers are displayed with.
+    pub fn numbering(&self) -> &Numbering {
+        &self.numbering
+    }
+
+    /// Transform a number.
+    pub fn map(&self, n: i64) -> i64 {
+        n.saturating_sub(1)
+            .saturating_mul(self.step)
+            .saturating_add(self.offset)
+    }
+}
+
+impl Repr for NumberingMap {
+    fn repr(&self) -> EcoString {
+        let mut parts = vec![self.numbering.as_ref().clone().into_value().repr()];
+        if self.offset != 1 {
+            parts.push(eco_format!("offset: {}", self.offset));
+        }
+        if self.step != 1 {
+            parts.push(eco_format!("step: {}", self.step));
+        }
+        eco_format!("numbering.map{}", repr::pretty_array_like(&parts, false))
+    }
+}
+
+/// A numbering that counts down from a total.
+///
+/// Created with [`numbering.reversed`]($numbering.reversed).
+#[ty(cast, name = "reversed-numbering")]
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct NumberingReversed {
+    /// The numbering to display the counted down numbers with.
+    numbering: Arc<Numbering>,
+    /// The number to count down from.
+    total: u64,
+}
+
+impl NumberingReversed {
+    /// The numbering the counted down numbers are displayed with.
+    pub fn numbering(&self) -> &Numbering {
+        &self.numbering
+    }
+
+    /// Count a number down from the total, stopping at zero.
+    pub fn reverse(&self, n: i64) -> i64 {
+        let total = i64::try_from(self.total).unwrap_or(i64::MAX);
+        total.saturating_sub(n).saturating_add(1).max(0)
+    }
+}
+
+impl Repr for NumberingReversed {
+    fn repr(&self) -> EcoString {
+        let parts = [
+            self.numbering.as_ref().clone().into_value().repr(),
+            eco_format!("{}", self.total),
+        ];
+        eco_format!("numbering.reversed{}", repr::pretty_array_like(&parts, false))
+    }
 }
 
 /// How to turn a number into text.
@@ -147,58 +923,279 @@ cast! {
 /// - `1)`
 /// - `a.`
 /// - `(I)`
+///
+/// A pattern without options is represented by its text as a string. Once
+/// configured, for example with [`numbering.pattern`]($numbering.pattern), it
+/// is a value of its own that keeps the options.
+#[ty(scope, cast, name = "numbering-pattern")]
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct NumberingPattern {
     pub pieces: EcoVec<(EcoString, NumberingKind)>,
     pub suffix: EcoString,
-    trimmed: bool,
+    /// The minimum number of digits of each piece.
+    widths: EcoVec<usize>,
+    /// The text each piece ends with when it is the last one rendered or is
+    /// followed by another piece. Empty if the piece has no suffix of its own.
+    suffixes: EcoVec<EcoString>,
+    /// What to display for zero instead of the kind's own representation.
+    zero: Option<EcoString>,
+    /// How to write Roman numerals of 4000 and above.
+    roman: RomanStyle,
+    /// Whether to mark Greek numerals with a keraia.
+    keraia: bool,
+    /// Whether to leave out the first prefix.
+    trim_prefix: bool,
+    /// Whether to leave out the suffix.
+    trim_suffix: bool,
+    /// What to do with more numbers than counting symbols.
+    overflow: NumberingOverflow,
+    /// What to do with fewer numbers than counting symbols.
+    underflow: NumberingUnderflow,
+    /// How many numbers to display at most before eliding the middle ones.
+    max_depth: Option<NonZeroUsize>,
+    /// What to display in place of the elided numbers.
+    ellipsis: EcoString,
+    /// The text the pattern was parsed from, exactly as written. Options that
+    /// can't be written in a pattern, like trimming, are not part of it.
+    source: EcoString,
+}
+
+#[scope]
+impl NumberingPattern {
+    /// Returns a copy of the pattern with the given options.
+    ///
+    /// Takes the same named arguments as the [`numbering`]($numbering)
+    /// function. Options that aren't given are kept as they are.
+    ///
+    /// ```example
+    /// #let pattern = numbering.pattern("(1.a)", trim: true)
+    /// #numbering(pattern, 1, 2, 3) \
+    /// #numbering(pattern.with(overflow: "truncate"), 1, 2, 3)
+    /// ```
+    #[func]
+    pub fn with(
+        self,
+        /// What to display for zero. See the [`numbering`]($numbering.zero)
+        /// function.
+        #[named]
+        zero: Option<Option<EcoString>>,
+        /// How to write large Roman numerals. See the
+        /// [`numbering`]($numbering.roman) function.
+        #[named]
+        roman: Option<RomanStyle>,
+        /// Whether to mark Greek numerals with a keraia. See the
+        /// [`numbering`]($numbering.keraia) function.
+        #[named]
+        keraia: Option<bool>,
+        /// Whether to leave out the first prefix and the suffix. See the
+        /// [`numbering`]($numbering.trim) function.
+        #[named]
+        trim: Option<NumberingTrim>,
+        /// What to do with more numbers than counting symbols. See the
+        /// [`numbering`]($numbering.overflow) function.
+        #[named]
+        overflow: Option<NumberingOverflow>,
+        /// What to do with fewer numbers than counting symbols. See the
+        /// [`numbering`]($numbering.underflow) function.
+        #[named]
+        underflow: Option<NumberingUnderflow>,
+        /// How many numbers to display at most. See the
+        /// [`numbering`]($numbering.max-depth) function.
+        #[named]
+        max_depth: Option<Option<NonZeroUsize>>,
+        /// What to display in place of the elided numbers. See the
+        /// [`numbering`]($numbering.ellipsis) function.
+        #[named]
+        ellipsis: Option<EcoString>,
+    ) -> NumberingPattern {
+        let options = NumberingOptions {
+            zero,
+            roman,
+            keraia,
+            trim,
+            overflow,
+            underflow,
+            max_depth,
+            ellipsis,
+        };
+        let mut pattern = self;
+        options.apply(&mut pattern);
+        pattern
+    }
 }
 
 impl NumberingPattern {
+    /// Parse a pattern from the string literal with the given span, pointing
+    /// errors to the offending part of the literal where possible.
+    fn parse_spanned(engine: &Engine, text: &str, span: Span) -> SourceResult<Self> {
+        text.parse().or_else(|err: NumberingPatternError| {
+            match err.narrow(engine, text, span) {
+                Some(narrowed) => Err(err.message).at(narrowed),
+                None => Err(err.locate(text)).at(span),
+            }
+        })
+    }
+
     /// Apply the pattern to the given number.
-    pub fn apply(&self, numbers: &[u64]) -> EcoString {
+    pub fn apply(&self, numbers: &[i64]) -> EcoString {
+        self.apply_localized(numbers, None)
+    }
+
+    /// Apply the pattern to the given number in a specific locale.
+    pub fn apply_localized(
+        &self,
+        numbers: &[i64],
+        locale: Option<NumberingLocale>,
+    ) -> EcoString {
+        self.apply_impl(numbers, None, locale)
+    }
+
+    /// Apply the pattern to a range of numbers in a specific locale.
+    ///
+    /// The last of the `from` numbers is displayed as a range up to `to`. The
+    /// numbers in front of it are displayed once.
+    pub fn apply_range_localized(
+        &self,
+        from: &[i64],
+        to: i64,
+        separator: &str,
+        collapse: bool,
+        locale: Option<NumberingLocale>,
+    ) -> EcoString {
+        self.apply_impl(from, Some(RangeEnd { to, separator, collapse }), locale)
+    }
+
+    /// Apply the pattern to numbers, the last of which may start a range.
+    fn apply_impl(
+        &self,
+        numbers: &[i64],
+        end: Option<RangeEnd>,
+        locale: Option<NumberingLocale>,
+    ) -> EcoString {
         let mut fmt = EcoString::new();
-        let mut numbers = numbers.iter();
+        let ranged = numbers.len().checked_sub(1);
+        let numbers = match self.overflow {
+            NumberingOverflow::Truncate => {
+                &numbers[..numbers.len().min(self.pieces.len())]
+            }
+            _ => numbers,
+        };
+        let given = numbers.len();
 
-        for (i, ((prefix, kind), &n)) in self.pieces.iter().zip(&mut numbers).enumerate()
-        {
-            if i > 0 || !self.trimmed {
-                fmt.push_str(prefix);
+        // The numbers between the first `max_depth - 1` ones and the last one
+        // are replaced by a single ellipsis.
+        let elided = match self.max_depth {
+            Some(depth) if given > depth.get() => depth.get() - 1..given - 1,
+            _ => 0..0,
+        };
+
+        // The own suffix of the most recently rendered piece, if it has one.
+        let mut own: Option<&EcoString> = None;
+
+        for (i, &n) in numbers.iter().enumerate() {
+            if elided.contains(&i) && i > elided.start {
+                continue;
             }
-            fmt.push_str(&kind.apply(n));
-        }
 
-        for ((prefix, kind), &n) in self.pieces.last().into_iter().cycle().zip(numbers) {
-            if prefix.is_empty() {
+            // Beyond the counting symbols, the last one is repeated.
+            let k = i.min(self.pieces.len() - 1);
+            let (prefix, kind) = &self.pieces[k];
+            if let Some(own) = own.take() {
+                fmt.push_str(own);
+                fmt.push_str(prefix);
+            } else if i >= self.pieces.len() && prefix.is_empty() {
                 fmt.push_str(&self.suffix);
-            } else {
+            } else if i > 0 || !self.trim_prefix {
                 fmt.push_str(prefix);
             }
-            fmt.push_str(&kind.apply(n));
+
+            if elided.contains(&i) {
+                fmt.push_str(&self.ellipsis);
+            } else {
+                let width = self.widths[k];
+                fmt.push_str(&self.apply_piece(*kind, width, n, locale));
+                if let Some(end) = end.as_ref().filter(|_| ranged == Some(i)) {
+                    self.push_range_end(&mut fmt, *kind, width, n, end, locale);
+                }
+            }
+            own = Some(&self.suffixes[k]).filter(|s| !s.is_empty());
+        }
+
+        if let NumberingUnderflow::Placeholder(placeholder) = &self.underflow {
+            let pieces = self.pieces.iter().zip(&self.suffixes).enumerate().skip(given);
+            for (i, ((prefix, _), suffix)) in pieces {
+                if let Some(own) = own.take() {
+                    fmt.push_str(own);
+                }
+                if i > 0 || !self.trim_prefix {
+                    fmt.push_str(prefix);
+                }
+                fmt.push_str(placeholder);
+                own = Some(suffix).filter(|s| !s.is_empty());
+            }
         }
 
-        if !self.trimmed {
-            fmt.push_str(&self.suffix);
+        if !self.trim_suffix {
+            fmt.push_str(own.unwrap_or(&self.suffix));
         }
 
         fmt
     }
 
+    /// Write the separator and the end of a range started by the number `from`,
+    /// unless the range consists of just that number.
+    fn push_range_end(
+        &self,
+        fmt: &mut EcoString,
+        kind: NumberingKind,
+        width: usize,
+        from: i64,
+        end: &RangeEnd,
+        locale: Option<NumberingLocale>,
+    ) {
+        if from == end.to {
+            return;
+        }
+
+        fmt.push_str(end.separator);
+        let to = self.apply_piece(kind, width, end.to, locale);
+        if end.collapse && kind == NumberingKind::Arabic {
+            let from = self.apply_piece(kind, width, from, locale);
+            fmt.push_str(collapsed(&from, &to));
+        } else {
+            fmt.push_str(&to);
+        }
+    }
+
     /// Apply only the k-th segment of the pattern to a number.
-    pub fn apply_kth(&self, k: usize, number: u64) -> EcoString {
+    pub fn apply_kth(&self, k: usize, number: i64) -> EcoString {
+        self.apply_kth_localized(k, number, None)
+    }
+
+    /// Apply only the k-th segment of the pattern to a number in a specific
+    /// locale.
+    pub fn apply_kth_localized(
+        &self,
+        k: usize,
+        number: i64,
+        locale: Option<NumberingLocale>,
+    ) -> EcoString {
         let mut fmt = EcoString::new();
         if let Some((prefix, _)) = self.pieces.first() {
             fmt.push_str(prefix);
         }
-        if let Some((_, kind)) = self
-            .pieces
-            .iter()
-            .chain(self.pieces.last().into_iter().cycle())
-            .nth(k)
+        let pieces = self.pieces.iter().zip(&self.widths).zip(&self.suffixes);
+        let last = self.pieces.last().zip(self.widths.last()).zip(self.suffixes.last());
+        let mut suffix = &self.suffix;
+        if let Some((((_, kind), &width), own)) =
+            pieces.chain(last.into_iter().cycle()).nth(k)
         {
-            fmt.push_str(&kind.apply(number));
+            fmt.push_str(&self.apply_piece(*kind, width, number, locale));
+            if !own.is_empty() {
+                suffix = own;
+            }
         }
-        fmt.push_str(&self.suffix);
+        fmt.push_str(suffix);
         fmt
     }
 
@@ -206,49 +1203,731 @@ impl NumberingPattern {
     pub fn pieces(&self) -> usize {
         self.pieces.len()
     }
-}
 
-impl FromStr for NumberingPattern {
-    type Err = &'static str;
+    /// Whether the numbers are written in the plain style of their kinds, with
+    /// nothing but the prefixes around them. This is not the case if the
+    /// pattern pads numbers, has suffixes for single counting symbols, writes
+    /// zero or Roman numerals differently, or leaves out its first prefix.
+    pub fn is_plain(&self) -> bool {
+        self.widths.iter().all(|&width| width == 1)
+            && self.suffixes.iter().all(EcoString::is_empty)
+            && self.zero.is_none()
+            && self.roman == RomanStyle::default()
+            && !self.trim_prefix
+    }
 
-    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
-        let mut pieces = EcoVec::new();
-        let mut handled = 0;
+    /// Recover the numbers from text displayed with this pattern.
+    ///
+    /// The prefixes and suffixes must match exactly and each number must be
+    /// written the way the pattern would write it, which is only supported for
+    /// the kinds that [`NumberingKind::parse`] supports. Parses that don't
+    /// need to repeat the last counting symbol are preferred. Returns `None`
+    /// if the text can't be parsed or could be parsed in multiple ways.
+    ///
+    /// To keep this fast for long text, the last counting symbol is repeated
+    /// at most 32 times and a single number may span at most 32 characters,
+    /// unless the pattern pads it to more.
+    pub fn parse(&self, text: &str) -> Option<Vec<u64>> {
+        let len = self.pieces.len();
+        let max = text.chars().count().min(len + PARSE_REPEAT_LIMIT);
+        let slots = self.layout(max);
+        for limit in [len.min(max), max] {
+            match self.solve(text, 0, &slots[..limit], &mut HashMap::new()) {
+                Reading::None => {}
+                Reading::One(mut numbers) => {
+                    numbers.reverse();
+                    return Some(numbers);
+                }
+                Reading::Many => return None,
+            }
+        }
+        None
+    }
 
-        for (i, c) in pattern.char_indices() {
-            let Some(kind) = NumberingKind::from_char(c) else {
-                continue;
+    /// Determine the text in front of each of the given amount of numbers,
+    /// with the index of the piece it is displayed with.
+    ///
+    /// The text in front of a number doesn't depend on how many numbers
+    /// follow it, so the layout for fewer numbers is a prefix of this one.
+    fn layout(&self, count: usize) -> Vec<(EcoString, usize)> {
+        let mut slots = Vec::with_capacity(count);
+        let mut own: Option<&EcoString> = None;
+        for i in 0..count {
+            let k = i.min(self.pieces.len() - 1);
+            let prefix = &self.pieces[k].0;
+            let text = match own.take() {
+                Some(own) => eco_format!("{own}{prefix}"),
+                None if i >= self.pieces.len() && prefix.is_empty() => {
+                    self.suffix.clone()
+                }
+                None => prefix.clone(),
             };
+            slots.push((text, k));
+            own = Some(&self.suffixes[k]).filter(|s| !s.is_empty());
+        }
+        slots
+    }
 
-            let prefix = pattern[handled..i].into();
-            pieces.push((prefix, kind));
-            handled = c.len_utf8() + i;
+    /// Find the ways to read numbers from the text starting at the given byte
+    /// offset, for the slots that remain.
+    ///
+    /// The numbers of a single reading are collected in reverse. Readings are
+    /// memoized by the amount of remaining slots and the offset, so that the
+    /// many ways to split up a text don't need to be explored more than once.
+    fn solve(
+        &self,
+        text: &str,
+        offset: usize,
+        slots: &[(EcoString, usize)],
+        memo: &mut HashMap<(usize, usize), Reading>,
+    ) -> Reading {
+        let key = (slots.len(), offset);
+        if let Some(reading) = memo.get(&key) {
+            return reading.clone();
         }
 
-        let suffix = pattern[handled..].into();
-        if pieces.is_empty() {
-            return Err("invalid numbering pattern");
+        let Some(((literal, k), rest)) = slots.split_first() else {
+            return Reading::None;
+        };
+
+        let mut reading = Reading::None;
+        if let Some(after) = text[offset..].strip_prefix(literal.as_str()) {
+            let start = text.len() - after.len();
+            let (kind, width) = (self.pieces[*k].1, self.widths[*k]);
+            let closing = Some(&self.suffixes[*k])
+                .filter(|s| !s.is_empty())
+                .unwrap_or(&self.suffix);
+            for (i, c) in after.char_indices().take(width.max(PARSE_NUMBER_LIMIT)) {
+                let end = i + c.len_utf8();
+                let Some(n) = kind.decode(&after[..end]) else { continue };
+                let Ok(signed) = i64::try_from(n) else { continue };
+                if self.apply_piece(kind, width, signed, None) != after[..end] {
+                    continue;
+                }
+
+                // The text may end after this number or continue with the
+                // next slot.
+                if &after[end..] == closing.as_str() {
+                    reading = reading.or(Reading::One(vec![n]));
+                }
+                reading = reading.or(match self.solve(text, start + end, rest, memo) {
+                    Reading::One(mut numbers) => {
+                        numbers.push(n);
+                        Reading::One(numbers)
+                    }
+                    other => other,
+                });
+
+                if reading == Reading::Many {
+                    break;
+                }
+            }
         }
 
-        Ok(Self { pieces, suffix, trimmed: false })
+        memo.insert(key, reading.clone());
+        reading
     }
-}
 
-cast! {
-    NumberingPattern,
-    self => {
-        let mut pat = EcoString::new();
-        for (prefix, kind) in &self.pieces {
-            pat.push_str(prefix);
-            pat.push(kind.to_char());
+    /// Check that the pattern can display the given amount of numbers.
+    ///
+    /// This only fails if the pattern is configured to reject more or fewer
+    /// numbers than it has counting symbols.
+    pub fn check(&self, count: usize) -> StrResult<()> {
+        let len = self.pieces.len();
+        if self.overflow == NumberingOverflow::Error && count > len {
+            bail!(
+                "too many numbers for pattern {} (given: {count}, counting symbols: {len})",
+                self.source.repr(),
+            );
         }
-        pat.push_str(&self.suffix);
-        pat.into_value()
-    },
-    v: Str => v.parse()?,
-}
+        if self.underflow == NumberingUnderflow::Error && count < len {
+            bail!(
+                "too few numbers for pattern {} (given: {count}, counting symbols: {len})",
+                self.source.repr(),
+            );
+        }
+        Ok(())
+    }
 
-/// Different kinds of numberings.
+    /// The options that differ from those of a freshly parsed pattern, named
+    /// like the arguments of the numbering function.
+    fn options(&self) -> Vec<(&'static str, Value)> {
+        let mut options = vec![];
+        if let Some(zero) = &self.zero {
+            options.push(("zero", zero.clone().into_value()));
+        }
+        if self.roman != RomanStyle::default() {
+            options.push(("roman", self.roman.into_value()));
+        }
+        if !self.keraia {
+            options.push(("keraia", false.into_value()));
+        }
+        let trim = NumberingTrim { prefix: self.trim_prefix, suffix: self.trim_suffix };
+        if trim != NumberingTrim::default() {
+            options.push(("trim", trim.into_value()));
+        }
+        if self.overflow != NumberingOverflow::default() {
+            options.push(("overflow", self.overflow.into_value()));
+        }
+        if self.underflow != NumberingUnderflow::default() {
+            options.push(("underflow", self.underflow.clone().into_value()));
+        }
+        if let Some(max_depth) = self.max_depth {
+            options.push(("max-depth", max_depth.into_value()));
+        }
+        if self.ellipsis != DEFAULT_ELLIPSIS {
+            options.push(("ellipsis", self.ellipsis.clone().into_value()));
+        }
+        options
+    }
+
+    /// The prefix and kind of the counting symbol at the given index.
+    fn piece(&self, index: usize) -> StrResult<&(EcoString, NumberingKind)> {
+        self.pieces.get(index).ok_or_else(|| {
+            eco_format!(
+                "pattern index out of bounds (index: {index}, len: {})",
+                self.pieces.len()
+            )
+        })
+    }
+
+    /// Apply a single counting symbol of the pattern to a number.
+    fn apply_piece(
+        &self,
+        kind: NumberingKind,
+        width: usize,
+        n: i64,
+        locale: Option<NumberingLocale>,
+    ) -> EcoString {
+        if let Some(zero) = self.zero.as_ref().filter(|_| n == 0) {
+            return zero.clone();
+        }
+
+        let magnitude = n.unsigned_abs();
+        let fmt = match kind {
+            NumberingKind::LowerRoman => {
+                roman_numeral(magnitude, Case::Lower, self.roman)
+            }
+            NumberingKind::UpperRoman => {
+                roman_numeral(magnitude, Case::Upper, self.roman)
+            }
+            NumberingKind::LowerGreek => {
+                greek_numeral(magnitude, Case::Lower, self.keraia)
+            }
+            NumberingKind::UpperGreek => {
+                greek_numeral(magnitude, Case::Upper, self.keraia)
+            }
+            _ => return padded(kind, width, n, locale),
+        };
+        kind.signed(n, fmt)
+    }
+}
+
+/// The end of a range of numbers displayed with a pattern.
+struct RangeEnd<'a> {
+    /// The last number of the range.
+    to: i64,
+    /// What to put between the start and the end of the range.
+    separator: &'a str,
+    /// Whether to leave out the leading digits the end shares with the start.
+    collapse: bool,
+}
+
+/// Leave out the leading digits that the end of a range shares with its
+/// start, keeping at least two digits, like "117–19".
+///
+/// Only numbers of the same length that consist of plain digits are collapsed.
+fn collapsed<'a>(from: &str, to: &'a str) -> &'a str {
+    if from.len() != to.len()
+        || !from.bytes().all(|b| b.is_ascii_digit())
+        || !to.bytes().all(|b| b.is_ascii_digit())
+    {
+        return to;
+    }
+
+    let shared = from.bytes().zip(to.bytes()).take_while(|(a, b)| a == b).count();
+    &to[shared.min(to.len().saturating_sub(2))..]
+}
+
+/// Apply a numbering kind and pad the result with its zero digit, if it has
+/// one, up to the given number of digits.
+///
+/// The minus sign of negative numbers does not count towards the width.
+fn padded(
+    kind: NumberingKind,
+    width: usize,
+    n: i64,
+    locale: Option<NumberingLocale>,
+) -> EcoString {
+    let mut fmt = kind.apply_unsigned(n.unsigned_abs(), locale);
+    if let Some(zero) = kind.zero_digit() {
+        let len = fmt.chars().count();
+        if len < width {
+            let mut zeros: EcoString = std::iter::repeat_n(zero, width - len).collect();
+            zeros.push_str(&fmt);
+            fmt = zeros;
+        }
+    }
+    kind.signed(n, fmt)
+}
+
+impl FromStr for NumberingPattern {
+    type Err = NumberingPatternError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        let mut pieces = EcoVec::new();
+        let mut widths = EcoVec::new();
+        let mut suffixes = EcoVec::<EcoString>::new();
+        let mut text = EcoString::new();
+        let mut rest = pattern;
+
+        while let Some(c) = rest.chars().next() {
+            let offset = pattern.len() - rest.len();
+            let (kind, width, len) = if let Some((quoted, len)) = quoted_text(rest) {
+                text.push_str(quoted);
+                rest = &rest[len..];
+                continue;
+            } else if let Some(after) = rest.strip_prefix("{|}") {
+                let Some(suffix) = suffixes.make_mut().last_mut() else {
+                    return Err(NumberingPatternError::new(
+                        offset..offset + 3,
+                        "`{|}` must follow a counting symbol",
+                    ));
+                };
+                suffix.push_str(&std::mem::take(&mut text));
+                rest = after;
+                continue;
+            } else if let Some(name) = named_token(rest) {
+                let len = name.len() + 2;
+                let Some(kind) = NumberingKind::from_name(name) else {
+                    return Err(NumberingPatternError::new(
+                        offset..offset + len,
+                        eco_format!("unknown counting symbol {{{name}}}"),
+                    ));
+                };
+                (kind, 1, len)
+            } else if let Some(padded) = padded_token(rest) {
+                padded
+            } else if let Some(kind) = NumberingKind::from_char(c)
+                .filter(|kind| !kind.standalone() || stands_alone(pattern, offset, c))
+            {
+                (kind, 1, c.len_utf8())
+            } else {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            };
+
+            pieces.push((std::mem::take(&mut text), kind));
+            widths.push(width);
+            suffixes.push(EcoString::new());
+            rest = &rest[len..];
+        }
+
+        let suffix = text;
+        if pattern.is_empty() {
+            return Err(NumberingPatternError::new(
+                0..0,
+                "numbering pattern must not be empty",
+            ));
+        } else if pieces.is_empty() {
+            return Err(NumberingPatternError::new(
+                0..pattern.len(),
+                "no counting symbol found",
+            ));
+        }
+
+        Ok(Self {
+            pieces,
+            suffix,
+            widths,
+            suffixes,
+            zero: None,
+            roman: RomanStyle::Vinculum,
+            keraia: true,
+            trim_prefix: false,
+            trim_suffix: false,
+            overflow: NumberingOverflow::Repeat,
+            underflow: NumberingUnderflow::Omit,
+            max_depth: None,
+            ellipsis: DEFAULT_ELLIPSIS.into(),
+            source: pattern.into(),
+        })
+    }
+}
+
+/// An error in a numbering pattern.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NumberingPatternError {
+    /// The byte range of the offending part of the pattern.
+    pub range: Range<usize>,
+    /// What is wrong with it.
+    pub message: EcoString,
+}
+
+impl NumberingPatternError {
+    /// Create an error about the given part of a pattern.
+    fn new(range: Range<usize>, message: impl Into<EcoString>) -> Self {
+        Self { range, message: message.into() }
+    }
+
+    /// The message along with where in the pattern the error is, for when the
+    /// diagnostic can't point there itself.
+    fn locate(&self, pattern: &str) -> EcoString {
+        if self.range == (0..pattern.len()) {
+            self.message.clone()
+        } else {
+            eco_format!("{} (at byte {})", self.message, self.range.start)
+        }
+    }
+
+    /// The span of the offending part of the string literal with the given
+    /// span. Only available if the literal contains the pattern verbatim,
+    /// without any escape sequences.
+    fn narrow(&self, engine: &Engine, pattern: &str, span: Span) -> Option<Span> {
+        if self.range.is_empty() {
+            return None;
+        }
+        let id = span.id()?;
+        let source = engine.world.source(id).ok()?;
+        let node = source.find(span)?;
+        let text = node.text();
+        if !node.is::<ast::Str>() || text.get(1..text.len() - 1)? != pattern {
+            return None;
+        }
+        let start = node.offset() + 1;
+        Some(Span::from_range(id, start + self.range.start..start + self.range.end))
+    }
+}
+
+impl Display for NumberingPatternError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Extract the name of a long-form token like `{kanji}` at the start of the
+/// text.
+///
+/// To keep single characters in braces working as before, the name must be
+/// at least two characters long. It may only consist of alphanumeric
+/// characters and hyphens and must be followed by a closing brace. Other
+/// braces, including unclosed ones, are just text.
+fn named_token(text: &str) -> Option<&str> {
+    let rest = text.strip_prefix('{')?;
+    let len = rest
+        .find(|c: char| !c.is_alphanumeric() && c != '-')
+        .unwrap_or(rest.len());
+    let name = &rest[..len];
+    (name.chars().nth(1).is_some() && rest[len..].starts_with('}')).then_some(name)
+}
+
+/// Extract the text of a quoted run like `{'Appendix'}` at the start of the
+/// text, along with the length of the whole run in bytes.
+///
+/// The quoted text ends at the first `'}`. Without one, the run is not quoted.
+fn quoted_text(text: &str) -> Option<(&str, usize)> {
+    let rest = text.strip_prefix("{'")?;
+    let end = rest.find("'}")?;
+    Some((&rest[..end], end + 4))
+}
+
+/// Whether the character at the given byte offset stands on its own rather
+/// than being part of a word.
+fn stands_alone(text: &str, offset: usize, c: char) -> bool {
+    let is_word = |c: char| c.is_alphabetic() || is_combining_mark(c);
+    !text[..offset].chars().next_back().is_some_and(is_word)
+        && !text[offset + c.len_utf8()..].chars().next().is_some_and(is_word)
+}
+
+/// Write the counting symbol of a kind, padded with zeros to the given width.
+fn write_symbol(kind: NumberingKind, width: usize, pat: &mut EcoString) {
+    match kind.to_name() {
+        Some(name) => {
+            pat.push('{');
+            pat.push_str(name);
+            pat.push('}');
+        }
+        None => {
+            if let Some(zero) = kind.zero_digit() {
+                pat.extend(std::iter::repeat_n(zero, width - 1));
+            }
+            pat.push(kind.to_char());
+        }
+    }
+}
+
+/// Recognize a run of zero digits followed by a decimal counting symbol, like
+/// `001`, at the start of the text.
+///
+/// Returns the kind, the number of digits and the length of the run in bytes.
+fn padded_token(text: &str) -> Option<(NumberingKind, usize, usize)> {
+    let zero = text.chars().next()?;
+    let zeros = text.chars().take_while(|&c| c == zero).count();
+    let len = zeros * zero.len_utf8();
+    let c = text[len..].chars().next()?;
+    let kind = NumberingKind::from_char(c)?;
+    (kind.zero_digit() == Some(zero)).then_some((kind, zeros + 1, len + c.len_utf8()))
+}
+
+impl Repr for NumberingPattern {
+    fn repr(&self) -> EcoString {
+        let options = self.options();
+        if options.is_empty() {
+            return self.source.repr();
+        }
+        let mut parts = vec![self.source.repr()];
+        parts.extend(
+            options
+                .iter()
+                .map(|(name, value)| eco_format!("{name}: {}", value.repr())),
+        );
+        eco_format!("numbering.pattern{}", repr::pretty_array_like(&parts, false))
+    }
+}
+
+cast! {
+    type NumberingPattern,
+    self => if self.options().is_empty() {
+        self.source.into_value()
+    } else {
+        Value::dynamic(self)
+    },
+    v: Str => v.parse().map_err(|err: NumberingPatternError| err.locate(&v))?,
+}
+
+/// How many more numbers than counting symbols [`NumberingPattern::parse`]
+/// reads at most.
+const PARSE_REPEAT_LIMIT: usize = 32;
+
+/// How many characters a single number read by [`NumberingPattern::parse`]
+/// spans at most, unless the pattern pads it to more.
+const PARSE_NUMBER_LIMIT: usize = 32;
+
+/// The ways to read numbers from text with a pattern.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Reading {
+    /// The text can't be read.
+    None,
+    /// The text can be read in exactly one way, yielding these numbers.
+    One(Vec<u64>),
+    /// The text can be read in multiple ways.
+    Many,
+}
+
+impl Reading {
+    /// Combine two alternative ways to read the same text.
+    fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::None, reading) | (reading, Self::None) => reading,
+            _ => Self::Many,
+        }
+    }
+}
+
+/// What a pattern displays in place of the numbers elided because of its
+/// maximum depth, unless configured otherwise.
+const DEFAULT_ELLIPSIS: &str = "…";
+
+/// The options of a numbering pattern, given as named arguments.
+///
+/// Options that aren't given leave the pattern as it is.
+struct NumberingOptions {
+    zero: Option<Option<EcoString>>,
+    roman: Option<RomanStyle>,
+    keraia: Option<bool>,
+    trim: Option<NumberingTrim>,
+    overflow: Option<NumberingOverflow>,
+    underflow: Option<NumberingUnderflow>,
+    max_depth: Option<Option<NonZeroUsize>>,
+    ellipsis: Option<EcoString>,
+}
+
+impl NumberingOptions {
+    /// Configure the pattern with the given options.
+    fn apply(self, pattern: &mut NumberingPattern) {
+        if let Some(zero) = self.zero {
+            pattern.zero = zero;
+        }
+        if let Some(roman) = self.roman {
+            pattern.roman = roman;
+        }
+        if let Some(keraia) = self.keraia {
+            pattern.keraia = keraia;
+        }
+        if let Some(trim) = self.trim {
+            pattern.trim_prefix = trim.prefix;
+            pattern.trim_suffix = trim.suffix;
+        }
+        if let Some(overflow) = self.overflow {
+            pattern.overflow = overflow;
+        }
+        if let Some(underflow) = self.underflow {
+            pattern.underflow = underflow;
+        }
+        if let Some(max_depth) = self.max_depth {
+            pattern.max_depth = max_depth;
+        }
+        if let Some(ellipsis) = self.ellipsis {
+            pattern.ellipsis = ellipsis;
+        }
+    }
+
+    /// The name of the first option that is given, if any.
+    fn first_given(&self) -> Option<&'static str> {
+        [
+            ("zero", self.zero.is_some()),
+            ("roman", self.roman.is_some()),
+            ("keraia", self.keraia.is_some()),
+            ("trim", self.trim.is_some()),
+            ("overflow", self.overflow.is_some()),
+            ("underflow", self.underflow.is_some()),
+            ("max-depth", self.max_depth.is_some()),
+            ("ellipsis", self.ellipsis.is_some()),
+        ]
+        .into_iter()
+        .find_map(|(name, given)| given.then_some(name))
+    }
+}
+
+/// The serialized form of a pattern: The text it was parsed from, along with
+/// how it is trimmed if it is.
+///
+/// The other configuration of a pattern is not serialized.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SerialPattern {
+    Plain(EcoString),
+    Trimmed { pattern: EcoString, trim: NumberingTrim },
+}
+
+impl Serialize for NumberingPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let pattern = self.source.clone();
+        let trim = NumberingTrim { prefix: self.trim_prefix, suffix: self.trim_suffix };
+        if trim == NumberingTrim::default() {
+            SerialPattern::Plain(pattern).serialize(serializer)
+        } else {
+            SerialPattern::Trimmed { pattern, trim }.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NumberingPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (pattern, trim) = match SerialPattern::deserialize(deserializer)? {
+            SerialPattern::Plain(pattern) => (pattern, NumberingTrim::default()),
+            SerialPattern::Trimmed { pattern, trim } => (pattern, trim),
+        };
+        let mut pattern: Self = pattern.parse().map_err(D::Error::custom)?;
+        pattern.trim_prefix = trim.prefix;
+        pattern.trim_suffix = trim.suffix;
+        Ok(pattern)
+    }
+}
+
+/// The language and region a numbering is applied in.
+///
+/// Most numbering kinds are the same in every locale, but some, like number
+/// words, depend on it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NumberingLocale {
+    /// The language.
+    pub lang: Lang,
+    /// The region, if any.
+    pub region: Option<Region>,
+}
+
+impl NumberingLocale {
+    /// Determine the locale from the text language and region in the styles.
+    pub fn new(styles: StyleChain) -> Self {
+        Self {
+            lang: TextElem::lang_in(styles),
+            region: TextElem::region_in(styles),
+        }
+    }
+}
+
+/// How to write Roman numerals of 4000 and above.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RomanStyle {
+    /// The thousands are written as a Roman numeral with an overline, like
+    /// I̅V̅ for 4000.
+    #[default]
+    Vinculum,
+    /// The thousands are written as repeated M, like MMMM for 4000. More than
+    /// twenty M are written as a multiple, like M×21.
+    Plain,
+}
+
+/// What to do with more numbers than a numbering pattern has counting symbols.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum NumberingOverflow {
+    /// Repeat the last counting symbol with its prefix.
+    #[default]
+    Repeat,
+    /// Leave out the numbers without a counting symbol.
+    Truncate,
+    /// Raise an error.
+    Error,
+}
+
+/// What to do with fewer numbers than a numbering pattern has counting
+/// symbols.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub enum NumberingUnderflow {
+    /// Leave out the counting symbols without a number and their prefixes.
+    #[default]
+    Omit,
+    /// Display the given text instead of the missing numbers.
+    Placeholder(EcoString),
+    /// Raise an error.
+    Error,
+}
+
+cast! {
+    NumberingUnderflow,
+    self => match self {
+        Self::Omit => Value::None,
+        Self::Placeholder(text) => Value::Str(text.into()),
+        Self::Error => Value::Str("error".into()),
+    },
+    /// Raise an error.
+    "error" => Self::Error,
+    _: NoneValue => Self::Omit,
+    v: Str => Self::Placeholder(v.into()),
+}
+
+/// Which parts of a numbering pattern to leave out.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct NumberingTrim {
+    /// Whether to leave out the first prefix.
+    pub prefix: bool,
+    /// Whether to leave out the suffix.
+    pub suffix: bool,
+}
+
+cast! {
+    NumberingTrim,
+    self => match (self.prefix, self.suffix) {
+        (true, false) => Value::Str("prefix".into()),
+        (false, true) => Value::Str("suffix".into()),
+        (both, _) => Value::Bool(both),
+    },
+    /// Leave out only the first prefix.
+    "prefix" => Self { prefix: true, suffix: false },
+    /// Leave out only the suffix.
+    "suffix" => Self { prefix: false, suffix: true },
+    v: bool => Self { prefix: v, suffix: v },
+}
+
+/// Different kinds of numberings.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum NumberingKind {
     /// Arabic numerals (1, 2, 3, etc.).
@@ -266,27 +1945,37 @@ pub enum NumberingKind {
     /// Uppercase Greek letters (Α, Β, Γ, etc.).
     UpperGreek,
     /// Paragraph/note-like symbols: *, †, ‡, §, ¶, and ‖. Further items use
-    /// repeated symbols.
+    /// repeated symbols, up to twenty repetitions.
     Symbol,
-    /// Hebrew numerals, including Geresh/Gershayim.
+    /// Hebrew numerals, including Geresh/Gershayim. Exact multiples of a
+    /// thousand are followed by the word for thousands (e.g. ה׳ אלפים for
+    /// 5000), so that they differ from the units marked with a geresh.
     Hebrew,
     /// Simplified Chinese standard numerals. This corresponds to the
     /// `ChineseCase::Lower` variant.
+    ///
+    /// In Taiwan, Hong Kong, and Macau, Traditional Chinese numerals are used
+    /// instead.
     LowerSimplifiedChinese,
     /// Simplified Chinese "banknote" numerals. This corresponds to the
     /// `ChineseCase::Upper` variant.
+    ///
+    /// In Taiwan, Hong Kong, and Macau, Traditional Chinese numerals are used
+    /// instead.
     UpperSimplifiedChinese,
-    // TODO: Pick the numbering pattern based on languages choice.
-    // As the first character of Simplified and Traditional Chinese numbering
-    // are the same, we are unable to determine if the context requires
-    // Simplified or Traditional by only looking at this character.
-    #[allow(unused)]
     /// Traditional Chinese standard numerals. This corresponds to the
     /// `ChineseCase::Lower` variant.
+    ///
+    /// As the counting symbols of Simplified and Traditional Chinese are the
+    /// same, patterns never produce this kind. Instead, the simplified kind
+    /// picks the variant based on the region.
     LowerTraditionalChinese,
-    #[allow(unused)]
     /// Traditional Chinese "banknote" numerals. This corresponds to the
     /// `ChineseCase::Upper` variant.
+    ///
+    /// As the counting symbols of Simplified and Traditional Chinese are the
+    /// same, patterns never produce this kind. Instead, the simplified kind
+    /// picks the variant based on the region.
     UpperTraditionalChinese,
     /// Hiragana in the gojūon order. Includes n but excludes wi and we.
     HiraganaAiueo,
@@ -314,6 +2003,205 @@ pub enum NumberingKind {
     CircledNumber,
     /// Double-circled numbers (⓵, ⓶, ⓷, etc.), up to 10.
     DoubleCircledNumber,
+    /// Lowercase Armenian numerals (ա, բ, գ, etc.), up to 9999. Larger numbers
+    /// fall back to Arabic numerals.
+    LowerArmenian,
+    /// Uppercase Armenian numerals (Ա, Բ, Գ, etc.), up to 9999. Larger numbers
+    /// fall back to Arabic numerals.
+    UpperArmenian,
+    /// Georgian numerals (ა, ბ, გ, etc.), up to 19999. Larger numbers fall back
+    /// to Arabic numerals.
+    Georgian,
+    /// Ethiopic numerals (፩, ፪, ፫, etc.).
+    Ethiopic,
+    /// Thai numerals.
+    ThaiNumber,
+    /// Thai letters (ก, ข, ค, ...กก, กข etc.). Excludes the obsolete ฃ and ฅ.
+    ThaiLetter,
+    /// Lao numerals.
+    LaoNumber,
+    /// Khmer numerals.
+    KhmerNumber,
+    /// Myanmar (Burmese) numerals.
+    MyanmarNumber,
+    /// Tibetan numerals.
+    TibetanNumber,
+    /// Tamil numerals.
+    TamilNumber,
+    /// Traditional Tamil numerals, using signs for ten (௰), hundred (௱), and
+    /// thousand (௲).
+    TamilTraditional,
+    /// Telugu numerals.
+    TeluguNumber,
+    /// Kannada numerals.
+    KannadaNumber,
+    /// Malayalam numerals.
+    MalayalamNumber,
+    /// Gujarati numerals.
+    GujaratiNumber,
+    /// Gurmukhi numerals.
+    GurmukhiNumber,
+    /// Odia numerals.
+    OdiaNumber,
+    /// Sinhala lith numerals.
+    SinhalaLithNumber,
+    /// Mongolian numerals.
+    MongolianNumber,
+    /// N'Ko numerals.
+    NkoNumber,
+    /// Adlam numerals.
+    AdlamNumber,
+    /// Lowercase Cyrillic letters (а, б, в, etc.). Items beyond я use base-28.
+    /// Excludes ё, й, ъ, ы, and ь, which are not used for enumeration.
+    LowerCyrillic,
+    /// Uppercase Cyrillic letters (А, Б, В, etc.). Items beyond Я use base-28.
+    /// Excludes Ё, Й, Ъ, Ы, and Ь, which are not used for enumeration.
+    UpperCyrillic,
+    /// Church Slavonic Cyrillic numerals with titlo (а҃, в҃, г҃, etc.), up to 9999.
+    /// Larger numbers fall back to Arabic numerals.
+    CyrillicNumeral,
+    /// Arabic letters in the abjad order (أ, ب, ج, etc.).
+    ArabicAbjad,
+    /// Arabic letters in the hijāʾī order (ا, ب, ت, etc.).
+    ArabicLetter,
+    /// Persian letters (الف, ب, پ, etc.). Multiple letters are separated by a
+    /// zero-width non-joiner.
+    PersianLetter,
+    /// Hebrew letters (א, ב, ג, etc.). Unlike the Hebrew numerals, items beyond ת
+    /// use base-22.
+    HebrewLetter,
+    /// Lowercase Greek letters (α, β, γ, etc.). Unlike the Greek numerals, items
+    /// beyond ω use base-24.
+    LowerGreekLetter,
+    /// Uppercase Greek letters (Α, Β, Γ, etc.). Unlike the Greek numerals, items
+    /// beyond Ω use base-24.
+    UpperGreekLetter,
+    /// Japanese kanji numerals (一, 二, 三, etc.). Unlike the Chinese numerals,
+    /// gaps between digits are not filled with a zero.
+    LowerJapaneseKanji,
+    /// Japanese formal daiji numerals (壱, 弐, 参, etc.). Unlike with the regular
+    /// kanji numerals, a one preceding a sign is always written out.
+    UpperJapaneseKanji,
+    /// Circled katakana in the gojūon order (㋐, ㋑, ㋒, etc.). Includes wi and we
+    /// but excludes n.
+    CircledKatakana,
+    /// Circled ideographs (㊀, ㊁, ㊂, etc.). Items beyond ㊉ use base-10 like the
+    /// double-circled numbers.
+    CircledIdeograph,
+    /// Parenthesized numbers (⑴, ⑵, ⑶, etc.), up to 20. Larger numbers are
+    /// composed of parentheses and Arabic numerals.
+    ParenthesizedNumber,
+    /// Parenthesized lowercase Latin letters (⒜, ⒝, ⒞, etc.), up to ⒵. Further
+    /// items are composed of parentheses and lowercase Latin letters.
+    ParenthesizedLowerLatin,
+    /// Parenthesized ideographs (㈠, ㈡, ㈢, etc.), up to 10. Larger numbers are
+    /// composed of fullwidth parentheses and Chinese numerals.
+    ParenthesizedIdeograph,
+    /// Fullwidth Arabic numerals (１, ２, ３, etc.).
+    FullwidthNumber,
+    /// Superscript Arabic numerals (¹, ², ³, etc.).
+    SuperscriptNumber,
+    /// Subscript Arabic numerals (₁, ₂, ₃, etc.).
+    SubscriptNumber,
+    /// Negative circled numbers (❶, ❷, ❸, etc.), up to 20. Items beyond ⓴ use
+    /// base-20 like the circled numbers.
+    BlackCircledNumber,
+    /// Sans-serif circled numbers (➀, ➁, ➂, etc.), up to 10. Items beyond ➉ use
+    /// base-10 like the double-circled numbers.
+    SansCircledNumber,
+    /// Negative sans-serif circled numbers (➊, ➋, ➌, etc.), up to 10. Items
+    /// beyond ➓ use base-10 like the double-circled numbers.
+    BlackSansCircledNumber,
+    /// Numbers with a full stop (⒈, ⒉, ⒊, etc.), up to 20. Larger numbers are
+    /// composed of Arabic numerals and a period.
+    NumberPeriod,
+    /// Tally marks (𝍷, 𝍷𝍷, 𝍷𝍷𝍷, etc.), grouped into bundles of five (𝍸). More than
+    /// twenty bundles are written as a multiple of the bundle.
+    TallyMark,
+    /// Counting rod numerals (𝍠, 𝍡, 𝍢, etc.), alternating between vertical and
+    /// horizontal forms from one digit to the next.
+    CountingRod,
+    /// Suzhou numerals (〡, 〢, 〣, etc.).
+    Suzhou,
+    /// Maya numerals (𝋡, 𝋢, 𝋣, etc.) in base 20. The digits are written one after
+    /// another instead of being stacked vertically.
+    Maya,
+    /// Kaktovik numerals (𝋁, 𝋂, 𝋃, etc.) in base 20.
+    Kaktovik,
+    /// Uppercase Roman numerals using the dedicated Unicode codepoints (Ⅰ, Ⅱ, Ⅲ,
+    /// etc.).
+    UpperRomanUnicode,
+    /// Lowercase Roman numerals using the dedicated Unicode codepoints (ⅰ, ⅱ, ⅲ,
+    /// etc.).
+    LowerRomanUnicode,
+    /// Clock face emoji (🕐, 🕑, 🕒, etc.). Items beyond 🕛 use base-12.
+    ClockFace,
+    /// Die faces (⚀, ⚁, ⚂, etc.). Items beyond ⚅ are written as a sum of dice.
+    /// More than twenty ⚅ are written as a multiple.
+    DieFace,
+    /// Braille number patterns (⠼⠁, ⠼⠃, ⠼⠉, etc.).
+    Braille,
+    /// Arabic numerals with English ordinal suffixes (1st, 2nd, 3rd, etc.).
+    EnglishOrdinal,
+    /// Lowercase English number words (one, two, three, etc.). Following American
+    /// usage, no "and" is inserted after the hundreds (one hundred five).
+    LowerEnglishCardinal,
+    /// Capitalized English number words (One, Two, Three, etc.). Following
+    /// American usage, no "and" is inserted after the hundreds (One Hundred
+    /// Five).
+    UpperEnglishCardinal,
+    /// Lowercase English ordinal words (first, second, third, etc.).
+    LowerEnglishOrdinalWord,
+    /// Capitalized English ordinal words (First, Second, Third, etc.).
+    UpperEnglishOrdinalWord,
+    /// Number words in the text language (one, two, three, etc.). Supported are
+    /// English, French, German, Spanish, and Ukrainian. Other languages fall
+    /// back to English.
+    Words,
+    /// Simplified Chinese ordinals (第一, 第二, 第三, etc.).
+    LowerChineseOrdinal,
+    /// Simplified Chinese ordinals with "banknote" numerals (第壹, 第贰, 第叁,
+    /// etc.).
+    UpperChineseOrdinal,
+    /// Bopomofo symbols (ㄅ, ㄆ, ㄇ, etc.). Items beyond ㄩ use base-37.
+    Bopomofo,
+    /// Korean Hanja numerals (一, 二, 三, etc.).
+    LowerKoreanHanja,
+    /// Korean formal Hanja numerals (壹, 貳, 參, etc.).
+    UpperKoreanHanja,
+    /// Native Korean number words (하나, 둘, 셋, etc.) up to 99. Other numbers
+    /// fall back to Arabic numerals.
+    KoreanNative,
+    /// Devanagari letters (क, ख, ग, ...कक, कख etc.).
+    DevanagariLetter,
+    /// Balinese numerals (᭑, ᭒, ᭓, etc.).
+    BalineseNumber,
+    /// Javanese numerals (꧑, ꧒, ꧓, etc.).
+    JavaneseNumber,
+    /// Cham numerals (꩑, ꩒, ꩓, etc.).
+    ChamNumber,
+    /// Vai numerals (꘡, ꘢, ꘣, etc.).
+    VaiNumber,
+    /// Osmanya numerals (𐒡, 𐒢, 𐒣, etc.).
+    OsmanyaNumber,
+    /// Tifinagh letters in the IRCAM order (ⴰ, ⴱ, ⴳ, etc.). Includes the
+    /// labialized ⴳⵯ and ⴽⵯ. Items beyond ⵥ use base-33.
+    Tifinagh,
+    /// Syriac letter numerals (ܐ, ܒ, ܓ, etc.), up to 9999. Larger numbers fall
+    /// back to Arabic numerals.
+    Syriac,
+    /// Coptic epact numbers (𐋡, 𐋢, 𐋣, etc.), up to 999. Larger numbers fall back
+    /// to Arabic numerals.
+    CopticEpact,
+    /// Glagolitic numerals (Ⰰ, Ⰱ, Ⰲ, etc.), up to 2999. Larger numbers fall back
+    /// to Arabic numerals.
+    Glagolitic,
+    /// Aegean numbers (𐄇, 𐄈, 𐄉, etc.), up to 99999. Larger numbers fall back to
+    /// Arabic numerals.
+    Aegean,
+    /// Elder Futhark runes in the fuþark order (ᚠ, ᚢ, ᚦ, etc.). Items beyond ᛟ use
+    /// base-24.
+    Runic,
 }
 
 impl NumberingKind {
@@ -344,24 +2232,245 @@ impl NumberingKind {
             '\u{0995}' => NumberingKind::BengaliLetter,
             '①' => NumberingKind::CircledNumber,
             '⓵' => NumberingKind::DoubleCircledNumber,
+            'ա' => NumberingKind::LowerArmenian,
+            'Ա' => NumberingKind::UpperArmenian,
+            'ა' => NumberingKind::Georgian,
+            '፩' => NumberingKind::Ethiopic,
+            '\u{0E51}' => NumberingKind::ThaiNumber,
+            'ก' => NumberingKind::ThaiLetter,
+            '\u{0ED1}' => NumberingKind::LaoNumber,
+            '\u{17E1}' => NumberingKind::KhmerNumber,
+            '\u{1041}' => NumberingKind::MyanmarNumber,
+            '\u{0F21}' => NumberingKind::TibetanNumber,
+            '\u{0BE7}' => NumberingKind::TamilNumber,
+            '௰' => NumberingKind::TamilTraditional,
+            '\u{0C67}' => NumberingKind::TeluguNumber,
+            '\u{0CE7}' => NumberingKind::KannadaNumber,
+            '\u{0D67}' => NumberingKind::MalayalamNumber,
+            '\u{0AE7}' => NumberingKind::GujaratiNumber,
+            '\u{0A67}' => NumberingKind::GurmukhiNumber,
+            '\u{0B67}' => NumberingKind::OdiaNumber,
+            '\u{0DE7}' => NumberingKind::SinhalaLithNumber,
+            '\u{1811}' => NumberingKind::MongolianNumber,
+            '\u{07C1}' => NumberingKind::NkoNumber,
+            '\u{1E951}' => NumberingKind::AdlamNumber,
+            'а' => NumberingKind::LowerCyrillic,
+            'А' => NumberingKind::UpperCyrillic,
+            'أ' => NumberingKind::ArabicAbjad,
+            'ا' => NumberingKind::ArabicLetter,
+            'آ' => NumberingKind::PersianLetter,
+            '壱' => NumberingKind::UpperJapaneseKanji,
+            '㋐' => NumberingKind::CircledKatakana,
+            '㊀' => NumberingKind::CircledIdeograph,
+            '⑴' => NumberingKind::ParenthesizedNumber,
+            '⒜' => NumberingKind::ParenthesizedLowerLatin,
+            '㈠' => NumberingKind::ParenthesizedIdeograph,
+            '\u{FF11}' => NumberingKind::FullwidthNumber,
+            '¹' => NumberingKind::SuperscriptNumber,
+            '\u{2081}' => NumberingKind::SubscriptNumber,
+            '❶' => NumberingKind::BlackCircledNumber,
+            '➀' => NumberingKind::SansCircledNumber,
+            '➊' => NumberingKind::BlackSansCircledNumber,
+            '⒈' => NumberingKind::NumberPeriod,
+            '\u{1D377}' => NumberingKind::TallyMark,
+            '\u{1D360}' => NumberingKind::CountingRod,
+            '〡' => NumberingKind::Suzhou,
+            '\u{1D2E1}' => NumberingKind::Maya,
+            '\u{1D2C1}' => NumberingKind::Kaktovik,
+            'Ⅰ' => NumberingKind::UpperRomanUnicode,
+            'ⅰ' => NumberingKind::LowerRomanUnicode,
+            '🕐' => NumberingKind::ClockFace,
+            '⚀' => NumberingKind::DieFace,
+            '⠼' => NumberingKind::Braille,
+            'ㄅ' => NumberingKind::Bopomofo,
+            '\u{0915}' => NumberingKind::DevanagariLetter,
+            '\u{1B51}' => NumberingKind::BalineseNumber,
+            '\u{A9D1}' => NumberingKind::JavaneseNumber,
+            '\u{AA51}' => NumberingKind::ChamNumber,
+            '\u{A621}' => NumberingKind::VaiNumber,
+            '\u{104A1}' => NumberingKind::OsmanyaNumber,
+            'ⴰ' => NumberingKind::Tifinagh,
+            'ܐ' => NumberingKind::Syriac,
+            '\u{102E1}' => NumberingKind::CopticEpact,
+            'Ⰰ' => NumberingKind::Glagolitic,
+            '\u{10107}' => NumberingKind::Aegean,
+            'ᚠ' => NumberingKind::Runic,
+            _ => return None,
+        })
+    }
+
+    /// Create a numbering kind from the name in a long-form token.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "cyrillic-numeral" => NumberingKind::CyrillicNumeral,
+            "hebrew-letter" => NumberingKind::HebrewLetter,
+            "greek-letter" => NumberingKind::LowerGreekLetter,
+            "Greek-letter" => NumberingKind::UpperGreekLetter,
+            "kanji" => NumberingKind::LowerJapaneseKanji,
+            "1st" => NumberingKind::EnglishOrdinal,
+            "one" => NumberingKind::LowerEnglishCardinal,
+            "One" => NumberingKind::UpperEnglishCardinal,
+            "first" => NumberingKind::LowerEnglishOrdinalWord,
+            "First" => NumberingKind::UpperEnglishOrdinalWord,
+            "words" => NumberingKind::Words,
+            "第一" => NumberingKind::LowerChineseOrdinal,
+            "第壹" => NumberingKind::UpperChineseOrdinal,
+            "hanja" => NumberingKind::LowerKoreanHanja,
+            "hanja-formal" => NumberingKind::UpperKoreanHanja,
+            "하나" => NumberingKind::KoreanNative,
+            _ => return None,
+        })
+    }
+
+    /// The name of this numbering kind in a long-form token, if it has no
+    /// representative character of its own.
+    pub fn to_name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::CyrillicNumeral => "cyrillic-numeral",
+            Self::HebrewLetter => "hebrew-letter",
+            Self::LowerGreekLetter => "greek-letter",
+            Self::UpperGreekLetter => "Greek-letter",
+            Self::LowerJapaneseKanji => "kanji",
+            Self::EnglishOrdinal => "1st",
+            Self::LowerEnglishCardinal => "one",
+            Self::UpperEnglishCardinal => "One",
+            Self::LowerEnglishOrdinalWord => "first",
+            Self::UpperEnglishOrdinalWord => "First",
+            Self::Words => "words",
+            Self::LowerChineseOrdinal => "第一",
+            Self::UpperChineseOrdinal => "第壹",
+            Self::LowerKoreanHanja => "hanja",
+            Self::UpperKoreanHanja => "hanja-formal",
+            Self::KoreanNative => "하나",
+            _ => return None,
+        })
+    }
+
+    /// The zero digit of this numbering kind, if it is a positional decimal
+    /// system. Only such kinds can be padded with zeros.
+    pub fn zero_digit(self) -> Option<char> {
+        Some(match self {
+            Self::Arabic => '0',
+            Self::EasternArabic => '\u{0660}',
+            Self::EasternArabicPersian => '\u{06F0}',
+            Self::DevanagariNumber => '\u{0966}',
+            Self::BengaliNumber => '\u{09E6}',
+            Self::ThaiNumber => '\u{0E50}',
+            Self::LaoNumber => '\u{0ED0}',
+            Self::KhmerNumber => '\u{17E0}',
+            Self::MyanmarNumber => '\u{1040}',
+            Self::TibetanNumber => '\u{0F20}',
+            Self::TamilNumber => '\u{0BE6}',
+            Self::TeluguNumber => '\u{0C66}',
+            Self::KannadaNumber => '\u{0CE6}',
+            Self::MalayalamNumber => '\u{0D66}',
+            Self::GujaratiNumber => '\u{0AE6}',
+            Self::GurmukhiNumber => '\u{0A66}',
+            Self::OdiaNumber => '\u{0B66}',
+            Self::SinhalaLithNumber => '\u{0DE6}',
+            Self::MongolianNumber => '\u{1810}',
+            Self::NkoNumber => '\u{07C0}',
+            Self::AdlamNumber => '\u{1E950}',
+            Self::FullwidthNumber => '\u{FF10}',
+            Self::SubscriptNumber => '\u{2080}',
+            Self::SuperscriptNumber => '⁰',
+            Self::BalineseNumber => '\u{1B50}',
+            Self::JavaneseNumber => '\u{A9D0}',
+            Self::ChamNumber => '\u{AA50}',
+            Self::VaiNumber => '\u{A620}',
+            Self::OsmanyaNumber => '\u{104A0}',
             _ => return None,
         })
     }
 
+    /// Whether the counting symbol of this kind is a letter that only counts
+    /// when it stands on its own. Within a word, it is just text.
+    pub fn standalone(self) -> bool {
+        matches!(
+            self,
+            Self::LowerArmenian
+                | Self::UpperArmenian
+                | Self::Georgian
+                | Self::ThaiLetter
+                | Self::LowerCyrillic
+                | Self::UpperCyrillic
+                | Self::ArabicAbjad
+                | Self::ArabicLetter
+                | Self::PersianLetter
+                | Self::DevanagariLetter
+                | Self::Tifinagh
+                | Self::Syriac
+                | Self::Glagolitic
+                | Self::Runic
+        )
+    }
+
+    /// Recover the number from its representation in this numbering kind.
+    ///
+    /// This is the inverse of [`apply`](Self::apply) for non-negative numbers.
+    /// It is supported for Arabic and the other decimal systems, Roman and
+    /// Greek numerals up to 3999 and 9999 respectively, Latin letters, and
+    /// Hebrew numerals. Returns `None` for other kinds and for text that this
+    /// kind would not produce, like `IIII`.
+    pub fn parse(self, text: &str) -> Option<u64> {
+        let n = self.decode(text)?;
+        (self.apply_unsigned(n, None) == text).then_some(n)
+    }
+
+    /// Read a number from text in this numbering kind without checking that
+    /// the text is its canonical representation.
+    fn decode(self, text: &str) -> Option<u64> {
+        match self {
+            Self::LowerRoman | Self::UpperRoman => decode_roman(text),
+            Self::LowerGreek | Self::UpperGreek => decode_greek(text),
+            Self::LowerLatin => decode_zeroless(text, 'a'..='z'),
+            Self::UpperLatin => decode_zeroless(text, 'A'..='Z'),
+            Self::Hebrew => decode_hebrew(text),
+            _ if self.zero_digit().is_some() => {
+                let digits: Vec<_> =
+                    (0..10).map(|d| self.apply_unsigned(d, None)).collect();
+                let mut n: u64 = 0;
+                for c in text.chars() {
+                    let d = digits.iter().position(|digit| digit.chars().eq([c]))?;
+                    n = n.checked_mul(10)?.checked_add(d as u64)?;
+                }
+                (!text.is_empty()).then_some(n)
+            }
+            _ => None,
+        }
+    }
+
     /// The representative character for this numbering kind.
+    ///
+    /// Kinds that have a [name](Self::to_name) share their character with
+    /// another kind.
     pub fn to_char(self) -> char {
         match self {
-            Self::Arabic => '1',
+            Self::Arabic
+            | Self::EnglishOrdinal
+            | Self::LowerEnglishCardinal
+            | Self::UpperEnglishCardinal
+            | Self::LowerEnglishOrdinalWord
+            | Self::UpperEnglishOrdinalWord
+            | Self::Words
+            | Self::KoreanNative => '1',
             Self::LowerLatin => 'a',
             Self::UpperLatin => 'A',
             Self::LowerRoman => 'i',
             Self::UpperRoman => 'I',
-            Self::LowerGreek => 'α',
-            Self::UpperGreek => 'Α',
+            Self::LowerGreek | Self::LowerGreekLetter => 'α',
+            Self::UpperGreek | Self::UpperGreekLetter => 'Α',
             Self::Symbol => '*',
-            Self::Hebrew => 'א',
-            Self::LowerSimplifiedChinese | Self::LowerTraditionalChinese => '一',
-            Self::UpperSimplifiedChinese | Self::UpperTraditionalChinese => '壹',
+            Self::Hebrew | Self::HebrewLetter => 'א',
+            Self::LowerSimplifiedChinese
+            | Self::LowerTraditionalChinese
+            | Self::LowerJapaneseKanji
+            | Self::LowerChineseOrdinal
+            | Self::LowerKoreanHanja => '一',
+            Self::UpperSimplifiedChinese
+            | Self::UpperTraditionalChinese
+            | Self::UpperChineseOrdinal
+            | Self::UpperKoreanHanja => '壹',
             Self::HiraganaAiueo => 'あ',
             Self::HiraganaIroha => 'い',
             Self::KatakanaAiueo => 'ア',
@@ -375,29 +2484,142 @@ impl NumberingKind {
             Self::BengaliLetter => '\u{0995}',
             Self::CircledNumber => '①',
             Self::DoubleCircledNumber => '⓵',
+            Self::LowerArmenian => 'ա',
+            Self::UpperArmenian => 'Ա',
+            Self::Georgian => 'ა',
+            Self::Ethiopic => '፩',
+            Self::ThaiNumber => '\u{0E51}',
+            Self::ThaiLetter => 'ก',
+            Self::LaoNumber => '\u{0ED1}',
+            Self::KhmerNumber => '\u{17E1}',
+            Self::MyanmarNumber => '\u{1041}',
+            Self::TibetanNumber => '\u{0F21}',
+            Self::TamilNumber => '\u{0BE7}',
+            Self::TamilTraditional => '௰',
+            Self::TeluguNumber => '\u{0C67}',
+            Self::KannadaNumber => '\u{0CE7}',
+            Self::MalayalamNumber => '\u{0D67}',
+            Self::GujaratiNumber => '\u{0AE7}',
+            Self::GurmukhiNumber => '\u{0A67}',
+            Self::OdiaNumber => '\u{0B67}',
+            Self::SinhalaLithNumber => '\u{0DE7}',
+            Self::MongolianNumber => '\u{1811}',
+            Self::NkoNumber => '\u{07C1}',
+            Self::AdlamNumber => '\u{1E951}',
+            Self::LowerCyrillic | Self::CyrillicNumeral => 'а',
+            Self::UpperCyrillic => 'А',
+            Self::ArabicAbjad => 'أ',
+            Self::ArabicLetter => 'ا',
+            Self::PersianLetter => 'آ',
+            Self::UpperJapaneseKanji => '壱',
+            Self::CircledKatakana => '㋐',
+            Self::CircledIdeograph => '㊀',
+            Self::ParenthesizedNumber => '⑴',
+            Self::ParenthesizedLowerLatin => '⒜',
+            Self::ParenthesizedIdeograph => '㈠',
+            Self::FullwidthNumber => '\u{FF11}',
+            Self::SuperscriptNumber => '¹',
+            Self::SubscriptNumber => '\u{2081}',
+            Self::BlackCircledNumber => '❶',
+            Self::SansCircledNumber => '➀',
+            Self::BlackSansCircledNumber => '➊',
+            Self::NumberPeriod => '⒈',
+            Self::TallyMark => '\u{1D377}',
+            Self::CountingRod => '\u{1D360}',
+            Self::Suzhou => '〡',
+            Self::Maya => '\u{1D2E1}',
+            Self::Kaktovik => '\u{1D2C1}',
+            Self::UpperRomanUnicode => 'Ⅰ',
+            Self::LowerRomanUnicode => 'ⅰ',
+            Self::ClockFace => '🕐',
+            Self::DieFace => '⚀',
+            Self::Braille => '⠼',
+            Self::Bopomofo => 'ㄅ',
+            Self::DevanagariLetter => '\u{0915}',
+            Self::BalineseNumber => '\u{1B51}',
+            Self::JavaneseNumber => '\u{A9D1}',
+            Self::ChamNumber => '\u{AA51}',
+            Self::VaiNumber => '\u{A621}',
+            Self::OsmanyaNumber => '\u{104A1}',
+            Self::Tifinagh => 'ⴰ',
+            Self::Syriac => 'ܐ',
+            Self::CopticEpact => '\u{102E1}',
+            Self::Glagolitic => 'Ⰰ',
+            Self::Aegean => '\u{10107}',
+            Self::Runic => 'ᚠ',
         }
     }
 
     /// Apply the numbering to the given number.
-    pub fn apply(self, n: u64) -> EcoString {
+    pub fn apply(self, n: i64) -> EcoString {
+        self.apply_localized(n, None)
+    }
+
+    /// Apply the numbering to the given number in a specific locale.
+    ///
+    /// Without a locale, kinds that depend on it use English. Negative numbers
+    /// are rendered as a minus sign followed by the representation of their
+    /// absolute value.
+    pub fn apply_localized(self, n: i64, locale: Option<NumberingLocale>) -> EcoString {
+        self.signed(n, self.apply_unsigned(n.unsigned_abs(), locale))
+    }
+
+    /// Put a minus sign in front of the representation of a number's absolute
+    /// value if the number is negative.
+    fn signed(self, n: i64, magnitude: EcoString) -> EcoString {
+        if n >= 0 {
+            return magnitude;
+        }
+
+        let mut fmt = EcoString::from(match self {
+            Self::EasternArabic => "\u{061C}-",
+            Self::EasternArabicPersian => "\u{200E}\u{2212}",
+            _ => "\u{2212}",
+        });
+        fmt.push_str(&magnitude);
+        fmt
+    }
+
+    /// Apply the numbering to a non-negative number.
+    fn apply_unsigned(self, n: u64, locale: Option<NumberingLocale>) -> EcoString {
         match self {
             Self::Arabic => eco_format!("{n}"),
-            Self::LowerRoman => roman_numeral(n, Case::Lower),
-            Self::UpperRoman => roman_numeral(n, Case::Upper),
-            Self::LowerGreek => greek_numeral(n, Case::Lower),
-            Self::UpperGreek => greek_numeral(n, Case::Upper),
-            Self::Symbol => {
-                if n == 0 {
-                    return '-'.into();
-                }
-
-                const SYMBOLS: &[char] = &['*', '†', '‡', '§', '¶', '‖'];
-                let n_symbols = SYMBOLS.len() as u64;
-                let symbol = SYMBOLS[((n - 1) % n_symbols) as usize];
-                let amount = ((n - 1) / n_symbols) + 1;
-                std::iter::repeat_n(symbol, amount.try_into().unwrap()).collect()
-            }
+            Self::LowerRoman => roman_numeral(n, Case::Lower, RomanStyle::Vinculum),
+            Self::UpperRoman => roman_numeral(n, Case::Upper, RomanStyle::Vinculum),
+            Self::LowerGreek => greek_numeral(n, Case::Lower, true),
+            Self::UpperGreek => greek_numeral(n, Case::Upper, true),
+            Self::Symbol => symbols(&["*", "†", "‡", "§", "¶", "‖"], n),
             Self::Hebrew => hebrew_numeral(n),
+            Self::LowerArmenian => armenian_numeral(n, Case::Lower),
+            Self::UpperArmenian => armenian_numeral(n, Case::Upper),
+            Self::Georgian => georgian_numeral(n),
+            Self::Ethiopic => ethiopic_numeral(n),
+            Self::TamilTraditional => tamil_numeral(n),
+            Self::LowerJapaneseKanji => kanji_numeral(n, Case::Lower),
+            Self::UpperJapaneseKanji => kanji_numeral(n, Case::Upper),
+            Self::LowerKoreanHanja => hanja_numeral(n, Case::Lower),
+            Self::UpperKoreanHanja => hanja_numeral(n, Case::Upper),
+            Self::KoreanNative => korean_native_numeral(n),
+            Self::Syriac => syriac_numeral(n),
+            Self::CopticEpact => coptic_epact_numeral(n),
+            Self::Glagolitic => glagolitic_numeral(n),
+            Self::Aegean => aegean_numeral(n),
+            Self::TallyMark => tally_marks(n),
+            Self::CountingRod => counting_rods(n),
+            Self::Suzhou => suzhou_numeral(n),
+            Self::CyrillicNumeral => cyrillic_numeral(n),
+            Self::UpperRomanUnicode => roman_numeral_unicode(n, Case::Upper),
+            Self::LowerRomanUnicode => roman_numeral_unicode(n, Case::Lower),
+            Self::DieFace => die_faces(n),
+            Self::Braille => braille_numeral(n),
+            Self::EnglishOrdinal => english_ordinal(n),
+            Self::LowerEnglishCardinal => english_cardinal(n, Case::Lower),
+            Self::UpperEnglishCardinal => english_cardinal(n, Case::Upper),
+            Self::LowerEnglishOrdinalWord => english_ordinal_word(n, Case::Lower),
+            Self::UpperEnglishOrdinalWord => english_ordinal_word(n, Case::Upper),
+            Self::Words => {
+                spell_out(locale.map_or(Lang::ENGLISH, |locale| locale.lang)).cardinal(n)
+            }
 
             Self::LowerLatin => zeroless(
                 [
@@ -475,6 +2697,14 @@ impl NumberingKind {
                 ],
                 n,
             ),
+            Self::ThaiLetter => zeroless(
+                [
+                    'ก', 'ข', 'ค', 'ฆ', 'ง', 'จ', 'ฉ', 'ช', 'ซ', 'ฌ', 'ญ', 'ฎ', 'ฏ', 'ฐ',
+                    'ฑ', 'ฒ', 'ณ', 'ด', 'ต', 'ถ', 'ท', 'ธ', 'น', 'บ', 'ป', 'ผ', 'ฝ', 'พ',
+                    'ฟ', 'ภ', 'ม', 'ย', 'ร', 'ล', 'ว', 'ศ', 'ษ', 'ส', 'ห', 'ฬ', 'อ', 'ฮ',
+                ],
+                n,
+            ),
             Self::CircledNumber => zeroless(
                 [
                     '①', '②', '③', '④', '⑤', '⑥', '⑦', '⑧', '⑨', '⑩', '⑪', '⑫', '⑬', '⑭',
@@ -488,84 +2718,1483 @@ impl NumberingKind {
             Self::DoubleCircledNumber => {
                 zeroless(['⓵', '⓶', '⓷', '⓸', '⓹', '⓺', '⓻', '⓼', '⓽', '⓾'], n)
             }
-
-            Self::LowerSimplifiedChinese => {
-                u64_to_chinese(ChineseVariant::Simple, ChineseCase::Lower, n).into()
-            }
-            Self::UpperSimplifiedChinese => {
-                u64_to_chinese(ChineseVariant::Simple, ChineseCase::Upper, n).into()
+            Self::LowerCyrillic => zeroless(
+                [
+                    'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'к', 'л', 'м', 'н', 'о',
+                    'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'э', 'ю', 'я',
+                ],
+                n,
+            ),
+            Self::UpperCyrillic => zeroless(
+                [
+                    'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'К', 'Л', 'М', 'Н', 'О',
+                    'П', 'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Э', 'Ю', 'Я',
+                ],
+                n,
+            ),
+            Self::ArabicAbjad => zeroless(
+                [
+                    'أ', 'ب', 'ج', 'د', 'ه', 'و', 'ز', 'ح', 'ط', 'ي', 'ك', 'ل', 'م', 'ن',
+                    'س', 'ع', 'ف', 'ص', 'ق', 'ر', 'ش', 'ت', 'ث', 'خ', 'ذ', 'ض', 'ظ', 'غ',
+                ],
+                n,
+            ),
+            Self::ArabicLetter => zeroless(
+                [
+                    'ا', 'ب', 'ت', 'ث', 'ج', 'ح', 'خ', 'د', 'ذ', 'ر', 'ز', 'س', 'ش', 'ص',
+                    'ض', 'ط', 'ظ', 'ع', 'غ', 'ف', 'ق', 'ك', 'ل', 'م', 'ن', 'ه', 'و', 'ي',
+                ],
+                n,
+            ),
+            Self::PersianLetter => zeroless_str(
+                [
+                    "الف", "ب", "پ", "ت", "ث", "ج", "چ", "ح", "خ", "د", "ذ", "ر", "ز",
+                    "ژ", "س", "ش", "ص", "ض", "ط", "ظ", "ع", "غ", "ف", "ق", "ک", "گ", "ل",
+                    "م", "ن", "و", "ه", "ی",
+                ],
+                "\u{200C}",
+                n,
+            ),
+            Self::HebrewLetter => zeroless(
+                [
+                    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט', 'י', 'כ', 'ל', 'מ', 'נ',
+                    'ס', 'ע', 'פ', 'צ', 'ק', 'ר', 'ש', 'ת',
+                ],
+                n,
+            ),
+            Self::LowerGreekLetter => zeroless(
+                [
+                    'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ',
+                    'ο', 'π', 'ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω',
+                ],
+                n,
+            ),
+            Self::UpperGreekLetter => zeroless(
+                [
+                    'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ',
+                    'Ο', 'Π', 'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω',
+                ],
+                n,
+            ),
+            Self::CircledKatakana => zeroless(
+                [
+                    '㋐', '㋑', '㋒', '㋓', '㋔', '㋕', '㋖', '㋗', '㋘', '㋙', '㋚',
+                    '㋛', '㋜', '㋝', '㋞', '㋟', '㋠', '㋡', '㋢', '㋣', '㋤', '㋥',
+                    '㋦', '㋧', '㋨', '㋩', '㋪', '㋫', '㋬', '㋭', '㋮', '㋯', '㋰',
+                    '㋱', '㋲', '㋳', '㋴', '㋵', '㋶', '㋷', '㋸', '㋹', '㋺', '㋻',
+                    '㋼', '㋽', '㋾',
+                ],
+                n,
+            ),
+            Self::CircledIdeograph => {
+                zeroless(['㊀', '㊁', '㊂', '㊃', '㊄', '㊅', '㊆', '㊇', '㊈', '㊉'], n)
             }
-            Self::LowerTraditionalChinese => {
-                u64_to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, n).into()
+            Self::ParenthesizedNumber => match n {
+                0..=20 => zeroless(
+                    [
+                        '⑴', '⑵', '⑶', '⑷', '⑸', '⑹', '⑺', '⑻', '⑼', '⑽', '⑾', '⑿', '⒀',
+                        '⒁', '⒂', '⒃', '⒄', '⒅', '⒆', '⒇',
+                    ],
+                    n,
+                ),
+                _ => eco_format!("({n})"),
+            },
+            Self::ParenthesizedLowerLatin => match n {
+                0..=26 => zeroless(
+                    [
+                        '⒜', '⒝', '⒞', '⒟', '⒠', '⒡', '⒢', '⒣', '⒤', '⒥', '⒦', '⒧', '⒨',
+                        '⒩', '⒪', '⒫', '⒬', '⒭', '⒮', '⒯', '⒰', '⒱', '⒲', '⒳', '⒴', '⒵',
+                    ],
+                    n,
+                ),
+                _ => eco_format!("({})", Self::LowerLatin.apply_unsigned(n, locale)),
+            },
+            Self::ParenthesizedIdeograph => match n {
+                0..=10 => zeroless(
+                    ['㈠', '㈡', '㈢', '㈣', '㈤', '㈥', '㈦', '㈧', '㈨', '㈩'],
+                    n,
+                ),
+                _ => eco_format!(
+                    "（{}）",
+                    Self::LowerSimplifiedChinese.apply_unsigned(n, locale)
+                ),
+            },
+            Self::BlackCircledNumber => zeroless(
+                [
+                    '❶', '❷', '❸', '❹', '❺', '❻', '❼', '❽', '❾', '❿', '⓫', '⓬', '⓭', '⓮',
+                    '⓯', '⓰', '⓱', '⓲', '⓳', '⓴',
+                ],
+                n,
+            ),
+            Self::SansCircledNumber => {
+                zeroless(['➀', '➁', '➂', '➃', '➄', '➅', '➆', '➇', '➈', '➉'], n)
             }
-            Self::UpperTraditionalChinese => {
-                u64_to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, n).into()
+            Self::BlackSansCircledNumber => {
+                zeroless(['➊', '➋', '➌', '➍', '➎', '➏', '➐', '➑', '➒', '➓'], n)
             }
-
+            Self::NumberPeriod => match n {
+                0..=20 => zeroless(
+                    [
+                        '⒈', '⒉', '⒊', '⒋', '⒌', '⒍', '⒎', '⒏', '⒐', '⒑', '⒒', '⒓', '⒔',
+                        '⒕', '⒖', '⒗', '⒘', '⒙', '⒚', '⒛',
+                    ],
+                    n,
+                ),
+                _ => eco_format!("{n}."),
+            },
+            Self::ClockFace => zeroless(
+                ['🕐', '🕑', '🕒', '🕓', '🕔', '🕕', '🕖', '🕗', '🕘', '🕙', '🕚', '🕛'],
+                n,
+            ),
+            Self::Bopomofo => zeroless(
+                [
+                    'ㄅ', 'ㄆ', 'ㄇ', 'ㄈ', 'ㄉ', 'ㄊ', 'ㄋ', 'ㄌ', 'ㄍ', 'ㄎ', 'ㄏ',
+                    'ㄐ', 'ㄑ', 'ㄒ', 'ㄓ', 'ㄔ', 'ㄕ', 'ㄖ', 'ㄗ', 'ㄘ', 'ㄙ', 'ㄚ',
+                    'ㄛ', 'ㄜ', 'ㄝ', 'ㄞ', 'ㄟ', 'ㄠ', 'ㄡ', 'ㄢ', 'ㄣ', 'ㄤ', 'ㄥ',
+                    'ㄦ', 'ㄧ', 'ㄨ', 'ㄩ',
+                ],
+                n,
+            ),
+            Self::DevanagariLetter => zeroless(
+                [
+                    'क', 'ख', 'ग', 'घ', 'ङ', 'च', 'छ', 'ज', 'झ', 'ञ', 'ट', 'ठ', 'ड', 'ढ',
+                    'ण', 'त', 'थ', 'द', 'ध', 'न', 'प', 'फ', 'ब', 'भ', 'म', 'य', 'र', 'ल',
+                    'व', 'श', 'ष', 'स', 'ह',
+                ],
+                n,
+            ),
+            Self::Tifinagh => zeroless_str(
+                [
+                    "ⴰ", "ⴱ", "ⴳ", "ⴳⵯ", "ⴷ", "ⴹ", "ⴻ", "ⴼ", "ⴽ", "ⴽⵯ", "ⵀ", "ⵃ", "ⵄ",
+                    "ⵅ", "ⵇ", "ⵉ", "ⵊ", "ⵍ", "ⵎ", "ⵏ", "ⵓ", "ⵔ", "ⵕ", "ⵖ", "ⵙ", "ⵚ", "ⵛ",
+                    "ⵜ", "ⵟ", "ⵡ", "ⵢ", "ⵣ", "ⵥ",
+                ],
+                "",
+                n,
+            ),
+            Self::Runic => zeroless(
+                [
+                    'ᚠ', 'ᚢ', 'ᚦ', 'ᚨ', 'ᚱ', 'ᚲ', 'ᚷ', 'ᚹ', 'ᚺ', 'ᚾ', 'ᛁ', 'ᛃ', 'ᛇ', 'ᛈ',
+                    'ᛉ', 'ᛊ', 'ᛏ', 'ᛒ', 'ᛖ', 'ᛗ', 'ᛚ', 'ᛜ', 'ᛞ', 'ᛟ',
+                ],
+                n,
+            ),
+
+            Self::LowerSimplifiedChinese => {
+                u64_to_chinese(chinese_variant(locale), ChineseCase::Lower, n).into()
+            }
+            Self::UpperSimplifiedChinese => {
+                u64_to_chinese(chinese_variant(locale), ChineseCase::Upper, n).into()
+            }
+            Self::LowerTraditionalChinese => {
+                u64_to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, n).into()
+            }
+            Self::UpperTraditionalChinese => {
+                u64_to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, n).into()
+            }
+            Self::LowerChineseOrdinal => {
+                eco_format!(
+                    "第{}",
+                    Self::LowerSimplifiedChinese.apply_unsigned(n, locale)
+                )
+            }
+            Self::UpperChineseOrdinal => {
+                eco_format!(
+                    "第{}",
+                    Self::UpperSimplifiedChinese.apply_unsigned(n, locale)
+                )
+            }
+
             Self::EasternArabic => decimal('\u{0660}', n),
             Self::EasternArabicPersian => decimal('\u{06F0}', n),
             Self::DevanagariNumber => decimal('\u{0966}', n),
             Self::BengaliNumber => decimal('\u{09E6}', n),
+            Self::ThaiNumber => decimal('\u{0E50}', n),
+            Self::LaoNumber => decimal('\u{0ED0}', n),
+            Self::KhmerNumber => decimal('\u{17E0}', n),
+            Self::MyanmarNumber => decimal('\u{1040}', n),
+            Self::TibetanNumber => decimal('\u{0F20}', n),
+            Self::TamilNumber => decimal('\u{0BE6}', n),
+            Self::TeluguNumber => decimal('\u{0C66}', n),
+            Self::KannadaNumber => decimal('\u{0CE6}', n),
+            Self::MalayalamNumber => decimal('\u{0D66}', n),
+            Self::GujaratiNumber => decimal('\u{0AE6}', n),
+            Self::GurmukhiNumber => decimal('\u{0A66}', n),
+            Self::OdiaNumber => decimal('\u{0B66}', n),
+            Self::SinhalaLithNumber => decimal('\u{0DE6}', n),
+            Self::MongolianNumber => decimal('\u{1810}', n),
+            Self::NkoNumber => decimal('\u{07C0}', n),
+            Self::AdlamNumber => decimal('\u{1E950}', n),
+            Self::FullwidthNumber => decimal('\u{FF10}', n),
+            Self::SubscriptNumber => decimal('\u{2080}', n),
+            Self::BalineseNumber => decimal('\u{1B50}', n),
+            Self::JavaneseNumber => decimal('\u{A9D0}', n),
+            Self::ChamNumber => decimal('\u{AA50}', n),
+            Self::VaiNumber => decimal('\u{A620}', n),
+            Self::OsmanyaNumber => decimal('\u{104A0}', n),
+            Self::SuperscriptNumber => {
+                positional(['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'], n)
+            }
+            Self::Maya => positional(
+                [
+                    '𝋠', '𝋡', '𝋢', '𝋣', '𝋤', '𝋥', '𝋦', '𝋧', '𝋨', '𝋩', '𝋪', '𝋫', '𝋬', '𝋭',
+                    '𝋮', '𝋯', '𝋰', '𝋱', '𝋲', '𝋳',
+                ],
+                n,
+            ),
+            Self::Kaktovik => positional(
+                [
+                    '𝋀', '𝋁', '𝋂', '𝋃', '𝋄', '𝋅', '𝋆', '𝋇', '𝋈', '𝋉', '𝋊', '𝋋', '𝋌', '𝋍',
+                    '𝋎', '𝋏', '𝋐', '𝋑', '𝋒', '𝋓',
+                ],
+                n,
+            ),
+        }
+    }
+}
+
+/// The letters of Hebrew numerals with their values, from largest to smallest.
+const HEBREW_NUMERALS: [(char, u64); 22] = [
+    ('ת', 400),
+    ('ש', 300),
+    ('ר', 200),
+    ('ק', 100),
+    ('צ', 90),
+    ('פ', 80),
+    ('ע', 70),
+    ('ס', 60),
+    ('נ', 50),
+    ('מ', 40),
+    ('ל', 30),
+    ('כ', 20),
+    ('י', 10),
+    ('ט', 9),
+    ('ח', 8),
+    ('ז', 7),
+    ('ו', 6),
+    ('ה', 5),
+    ('ד', 4),
+    ('ג', 3),
+    ('ב', 2),
+    ('א', 1),
+];
+
+/// Whether to use Simplified or Traditional Chinese numerals in a locale.
+///
+/// Traditional Chinese is used in Taiwan, Hong Kong, and Macau. Everywhere
+/// else and without a locale, Simplified Chinese is used.
+fn chinese_variant(locale: Option<NumberingLocale>) -> ChineseVariant {
+    match locale {
+        Some(NumberingLocale { lang, region: Some(region) })
+            if lang == Lang::CHINESE && matches!(region.as_str(), "TW" | "HK" | "MO") =>
+        {
+            ChineseVariant::Traditional
+        }
+        _ => ChineseVariant::Simple,
+    }
+}
+
+/// Stringify an integer to a Hebrew number.
+///
+/// Thousands are written as a prefix of letters followed by a geresh (e.g.
+/// 5784 is ה׳תשפ״ד). The gershayim or geresh of the remainder are placed as
+/// if there were no prefix. Without a remainder, the word for thousand or
+/// thousands follows instead (e.g. 1000 is א׳ אלף and 5000 is ה׳ אלפים), as
+/// the prefix alone would look like a unit with a geresh.
+fn hebrew_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+
+    let (thousands, mut n) = (n / 1000, n % 1000);
+    let mut fmt = EcoString::new();
+    if thousands > 0 {
+        fmt.push_str(&hebrew_letters(thousands));
+        fmt.push('׳');
+        if n == 0 {
+            fmt.push_str(if thousands == 1 { " אלף" } else { " אלפים" });
+            return fmt;
+        }
+    }
+
+    let mut units = EcoString::new();
+    'outer: for (name, value) in HEBREW_NUMERALS {
+        while n >= value {
+            match n {
+                15 => units.push_str("ט״ו"),
+                16 => units.push_str("ט״ז"),
+                _ => {
+                    let append_geresh = n == value && units.is_empty();
+                    if n == value && !units.is_empty() {
+                        units.push('״');
+                    }
+                    units.push(name);
+                    if append_geresh {
+                        units.push('׳');
+                    }
+
+                    n -= value;
+                    continue;
+                }
+            }
+            break 'outer;
+        }
+    }
+
+    fmt.push_str(&units);
+    fmt
+}
+
+/// Stringify an integer to plain Hebrew letters, without gershayim.
+///
+/// This is used for the thousands prefix of [`hebrew_numeral`]. Larger
+/// multiples of a thousand are again written as a prefix with a geresh.
+fn hebrew_letters(n: u64) -> EcoString {
+    let (thousands, mut n) = (n / 1000, n % 1000);
+    let mut fmt = EcoString::new();
+    if thousands > 0 {
+        fmt.push_str(&hebrew_letters(thousands));
+        fmt.push('׳');
+    }
+
+    for (name, value) in HEBREW_NUMERALS {
+        // 15 and 16 are written as 9 + 6 and 9 + 7.
+        while n >= value && !(value == 10 && (n == 15 || n == 16)) {
+            fmt.push(name);
+            n -= value;
+        }
+    }
+    fmt
+}
+
+/// Stringify an integer to an Armenian numeral.
+///
+/// Armenian numerals use a distinct letter for each digit of each power of ten
+/// and are composed additively, e.g. 12 is written as "ԺԲ" (10 + 2).
+fn armenian_numeral(n: u64, case: Case) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    match additive(
+        &[
+            &['Ա', 'Բ', 'Գ', 'Դ', 'Ե', 'Զ', 'Է', 'Ը', 'Թ'],
+            &['Ժ', 'Ի', 'Լ', 'Խ', 'Ծ', 'Կ', 'Հ', 'Ձ', 'Ղ'],
+            &['Ճ', 'Մ', 'Յ', 'Ն', 'Շ', 'Ո', 'Չ', 'Պ', 'Ջ'],
+            &['Ռ', 'Ս', 'Վ', 'Տ', 'Ր', 'Ց', 'Ւ', 'Փ', 'Ք'],
+        ],
+        n,
+    ) {
+        Some(fmt) => case.apply(&fmt).into(),
+        None => eco_format!("{n}"),
+    }
+}
+
+/// Stringify an integer to a Georgian numeral.
+///
+/// Like Armenian numerals, Georgian numerals use a letter for each digit of
+/// each power of ten. There is an additional letter for 10000, so numbers up
+/// to 19999 can be represented.
+fn georgian_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    additive(
+        &[
+            &['ა', 'ბ', 'გ', 'დ', 'ე', 'ვ', 'ზ', 'ჱ', 'თ'],
+            &['ი', 'კ', 'ლ', 'მ', 'ნ', 'ჲ', 'ო', 'პ', 'ჟ'],
+            &['რ', 'ს', 'ტ', 'ჳ', 'ფ', 'ქ', 'ღ', 'ყ', 'შ'],
+            &['ჩ', 'ც', 'ძ', 'წ', 'ჭ', 'ხ', 'ჴ', 'ჯ', 'ჰ'],
+            &['ჵ'],
+        ],
+        n,
+    )
+    .unwrap_or_else(|| eco_format!("{n}"))
+}
+
+/// Stringify an integer to a Coptic epact number.
+///
+/// Like Armenian numerals, Coptic epact numbers use a distinct sign for each
+/// digit of the units, tens, and hundreds. They have no signs for thousands, so
+/// larger numbers are written as Arabic numerals.
+fn coptic_epact_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    additive(
+        &[
+            &['𐋡', '𐋢', '𐋣', '𐋤', '𐋥', '𐋦', '𐋧', '𐋨', '𐋩'],
+            &['𐋪', '𐋫', '𐋬', '𐋭', '𐋮', '𐋯', '𐋰', '𐋱', '𐋲'],
+            &['𐋳', '𐋴', '𐋵', '𐋶', '𐋷', '𐋸', '𐋹', '𐋺', '𐋻'],
+        ],
+        n,
+    )
+    .unwrap_or_else(|| eco_format!("{n}"))
+}
+
+/// Stringify an integer to a Glagolitic numeral.
+///
+/// Like Armenian numerals, Glagolitic numerals use a letter for each digit of
+/// each power of ten. The letters are assigned in the order of the Glagolitic
+/// alphabet, so unlike with Cyrillic numerals, Ⰱ has the value two. Only the
+/// thousands up to 2000 have a letter.
+fn glagolitic_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    additive(
+        &[
+            &['Ⰰ', 'Ⰱ', 'Ⰲ', 'Ⰳ', 'Ⰴ', 'Ⰵ', 'Ⰶ', 'Ⰷ', 'Ⰸ'],
+            &['Ⰹ', 'Ⰻ', 'Ⰼ', 'Ⰽ', 'Ⰾ', 'Ⰿ', 'Ⱀ', 'Ⱁ', 'Ⱂ'],
+            &['Ⱃ', 'Ⱄ', 'Ⱅ', 'Ⱆ', 'Ⱇ', 'Ⱈ', 'Ⱉ', 'Ⱋ', 'Ⱌ'],
+            &['Ⱍ', 'Ⱎ'],
+        ],
+        n,
+    )
+    .unwrap_or_else(|| eco_format!("{n}"))
+}
+
+/// Stringify an integer to an Aegean number.
+///
+/// Like Armenian numerals, Aegean numbers use a distinct sign for each digit of
+/// each power of ten, up to the ten thousands.
+fn aegean_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    additive(
+        &[
+            &['𐄇', '𐄈', '𐄉', '𐄊', '𐄋', '𐄌', '𐄍', '𐄎', '𐄏'],
+            &['𐄐', '𐄑', '𐄒', '𐄓', '𐄔', '𐄕', '𐄖', '𐄗', '𐄘'],
+            &['𐄙', '𐄚', '𐄛', '𐄜', '𐄝', '𐄞', '𐄟', '𐄠', '𐄡'],
+            &['𐄢', '𐄣', '𐄤', '𐄥', '𐄦', '𐄧', '𐄨', '𐄩', '𐄪'],
+            &['𐄫', '𐄬', '𐄭', '𐄮', '𐄯', '𐄰', '𐄱', '𐄲', '𐄳'],
+        ],
+        n,
+    )
+    .unwrap_or_else(|| eco_format!("{n}"))
+}
+
+/// Stringify an integer to an Ethiopic numeral.
+///
+/// Ethiopic numerals have symbols for the ones and tens, but no zero. The
+/// number is split into groups of two digits, which are separated by the
+/// hundred (፻) and ten thousand (፼) markers in alternation. This follows the
+/// algorithm of the [`ethiopic-numeric`][css] CSS counter style.
+///
+/// [css]: https://www.w3.org/TR/css-counter-styles-3/#ethiopic-numeric-counter-style
+fn ethiopic_numeral(mut n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    } else if n == 1 {
+        return '፩'.into();
+    }
+
+    const ONES: [char; 9] = ['፩', '፪', '፫', '፬', '፭', '፮', '፯', '፰', '፱'];
+    const TENS: [char; 9] = ['፲', '፳', '፴', '፵', '፶', '፷', '፸', '፹', '፺'];
+
+    // Split the number into groups of two digits, least significant first.
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push(n % 100);
+        n /= 100;
+    }
+
+    let mut fmt = EcoString::new();
+    for (i, &group) in groups.iter().enumerate().rev() {
+        let odd = i % 2 == 1;
+
+        // A one is implied by the marker if it is in an odd group or leads
+        // the number.
+        let implied = group == 1 && (odd || i + 1 == groups.len());
+        if !implied {
+            let (tens, ones) = ((group / 10) as usize, (group % 10) as usize);
+            if tens != 0 {
+                fmt.push(TENS[tens - 1]);
+            }
+            if ones != 0 {
+                fmt.push(ONES[ones - 1]);
+            }
+        }
+
+        if odd && group != 0 {
+            fmt.push('፻');
+        } else if !odd && i > 0 {
+            fmt.push('፼');
+        }
+    }
+    fmt
+}
+
+/// Stringify an integer to a traditional Tamil numeral.
+///
+/// The signs for ten, hundred, and thousand are preceded by the digit they are
+/// multiplied with, unless it is one. The number of thousands is itself
+/// written as a Tamil numeral, so there is no upper limit.
+fn tamil_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+
+    const DIGITS: [char; 9] = ['௧', '௨', '௩', '௪', '௫', '௬', '௭', '௮', '௯'];
+
+    let mut fmt = EcoString::new();
+    let thousands = n / 1000;
+    if thousands > 1 {
+        fmt.push_str(&tamil_numeral(thousands));
+    }
+    if thousands > 0 {
+        fmt.push('௲');
+    }
+
+    for (value, sign) in [(100, Some('௱')), (10, Some('௰')), (1, None)] {
+        let digit = (n % (value * 10) / value) as usize;
+        if digit == 0 {
+            continue;
+        }
+        if digit > 1 || sign.is_none() {
+            fmt.push(DIGITS[digit - 1]);
+        }
+        if let Some(sign) = sign {
+            fmt.push(sign);
+        }
+    }
+    fmt
+}
+
+/// Stringify an integer to Japanese kanji numerals.
+///
+/// The signs for ten, hundred, and thousand are preceded by the digit they are
+/// multiplied with. For the regular numerals, this digit is omitted if it is
+/// one, while the formal daiji numerals always write it out to prevent
+/// forgery. Larger numbers are split into groups of four digits, each followed
+/// by the sign for its power of ten thousand. Like with the Chinese and Hanja
+/// numerals, these groups are always written out, so 10000 is 一万.
+fn kanji_numeral(mut n: u64, case: Case) -> EcoString {
+    if n == 0 {
+        return '〇'.into();
+    }
+
+    let (digits, ten, myriad) = match case {
+        Case::Lower => {
+            (['一', '二', '三', '四', '五', '六', '七', '八', '九'], '十', '万')
+        }
+        Case::Upper => {
+            (['壱', '弐', '参', '四', '五', '六', '七', '八', '九'], '拾', '萬')
+        }
+    };
+
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push(n % 10000);
+        n /= 10000;
+    }
+
+    let mut fmt = EcoString::new();
+    for (i, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        for (value, sign) in
+            [(1000, Some('千')), (100, Some('百')), (10, Some(ten)), (1, None)]
+        {
+            let digit = (group / value % 10) as usize;
+            if digit == 0 {
+                continue;
+            }
+            if digit > 1 || sign.is_none() || case == Case::Upper {
+                fmt.push(digits[digit - 1]);
+            }
+            if let Some(sign) = sign {
+                fmt.push(sign);
+            }
+        }
+        if i > 0 {
+            fmt.push([myriad, '億', '兆', '京'][i - 1]);
+        }
+    }
+    fmt
+}
+
+/// Stringify an integer to Korean Hanja numerals.
+///
+/// Like with the Japanese numerals, zero digits are skipped and the regular
+/// numerals omit the digit one in front of ten, hundred, and thousand, while
+/// the formal numerals always write it out. The groups in front of the signs
+/// for ten thousand and beyond are always written out, so 10000 is 一萬.
+fn hanja_numeral(mut n: u64, case: Case) -> EcoString {
+    if n == 0 {
+        return '零'.into();
+    }
+
+    let (digits, [ten, hundred, thousand]) = match case {
+        Case::Lower => {
+            (['一', '二', '三', '四', '五', '六', '七', '八', '九'], ['十', '百', '千'])
+        }
+        Case::Upper => {
+            (['壹', '貳', '參', '肆', '伍', '陸', '柒', '捌', '玖'], ['拾', '佰', '仟'])
+        }
+    };
+
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push(n % 10000);
+        n /= 10000;
+    }
+
+    let mut fmt = EcoString::new();
+    for (i, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        for (value, sign) in
+            [(1000, Some(thousand)), (100, Some(hundred)), (10, Some(ten)), (1, None)]
+        {
+            let digit = (group / value % 10) as usize;
+            if digit == 0 {
+                continue;
+            }
+            if digit > 1 || sign.is_none() || case == Case::Upper {
+                fmt.push(digits[digit - 1]);
+            }
+            if let Some(sign) = sign {
+                fmt.push(sign);
+            }
+        }
+        if i > 0 {
+            fmt.push(['萬', '億', '兆', '京'][i - 1]);
+        }
+    }
+    fmt
+}
+
+/// Stringify an integer to native Korean number words.
+///
+/// Native Korean numerals only exist from 1 to 99, so other numbers are
+/// written as Arabic numerals.
+fn korean_native_numeral(n: u64) -> EcoString {
+    const ONES: [&str; 10] =
+        ["", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉"];
+    const TENS: [&str; 10] =
+        ["", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔"];
+
+    if !(1..=99).contains(&n) {
+        return eco_format!("{n}");
+    }
+
+    eco_format!("{}{}", TENS[(n / 10) as usize], ONES[(n % 10) as usize])
+}
+
+/// Stringify an integer to a Syriac letter numeral.
+///
+/// Each digit is written as a letter and the values are summed up. As there
+/// are letters only up to 400, the hundreds from 500 on are composed of ܬ (400)
+/// and another hundred. Thousands are written as their units letter with an
+/// oblique line below.
+fn syriac_numeral(n: u64) -> EcoString {
+    const UNITS: [char; 9] = ['ܐ', 'ܒ', 'ܓ', 'ܕ', 'ܗ', 'ܘ', 'ܙ', 'ܚ', 'ܛ'];
+    const TENS: [char; 9] = ['ܝ', 'ܟ', 'ܠ', 'ܡ', 'ܢ', 'ܣ', 'ܥ', 'ܦ', 'ܨ'];
+    const HUNDREDS: [&str; 9] = ["ܩ", "ܪ", "ܫ", "ܬ", "ܬܩ", "ܬܪ", "ܬܫ", "ܬܬ", "ܬܬܩ"];
+
+    if n == 0 {
+        return '-'.into();
+    } else if n > 9999 {
+        return eco_format!("{n}");
+    }
+
+    let mut fmt = EcoString::new();
+    let digit = |place: u32| (n / 10_u64.pow(place) % 10) as usize;
+    if digit(3) > 0 {
+        fmt.push(UNITS[digit(3) - 1]);
+        fmt.push('\u{0748}');
+    }
+    if digit(2) > 0 {
+        fmt.push_str(HUNDREDS[digit(2) - 1]);
+    }
+    if digit(1) > 0 {
+        fmt.push(TENS[digit(1) - 1]);
+    }
+    if digit(0) > 0 {
+        fmt.push(UNITS[digit(0) - 1]);
+    }
+    fmt
+}
+
+/// Stringify an integer to a Church Slavonic Cyrillic numeral.
+///
+/// Like Armenian numerals, Cyrillic numerals use a letter for each digit of
+/// each power of ten. The thousands reuse the letters of the ones with a
+/// preceding thousands sign (҂). The ones are written before the ten for
+/// numbers from 11 to 19 and the titlo is placed over the penultimate letter.
+fn cyrillic_numeral(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+
+    const ONES: [char; 9] = ['а', 'в', 'г', 'д', 'є', 'ѕ', 'з', 'и', 'ѳ'];
+    let Some(letters) = additive(
+        &[
+            &ONES,
+            &['і', 'к', 'л', 'м', 'н', 'ѯ', 'ѻ', 'п', 'ч'],
+            &['р', 'с', 'т', 'у', 'ф', 'х', 'ѱ', 'ѿ', 'ц'],
+            &ONES,
+        ],
+        n,
+    ) else {
+        return eco_format!("{n}");
+    };
+
+    let mut letters: Vec<char> = letters.chars().collect();
+    if (11..20).contains(&(n % 100)) {
+        let len = letters.len();
+        letters.swap(len - 2, len - 1);
+    }
+
+    let titlo = letters.len().saturating_sub(2);
+    let mut fmt = EcoString::new();
+    for (i, &letter) in letters.iter().enumerate() {
+        if i == 0 && n >= 1000 {
+            fmt.push('҂');
+        }
+        fmt.push(letter);
+        if i == titlo {
+            fmt.push('\u{0483}');
+        }
+    }
+    fmt
+}
+
+/// Stringify a number by cycling through symbols.
+///
+/// Once all symbols are used up, they are repeated: The second cycle uses each
+/// symbol twice, the third thrice, and so on.
+fn symbols(symbols: &[impl AsRef<str>], n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+
+    let n_symbols = symbols.len() as u64;
+    let symbol = symbols[((n - 1) % n_symbols) as usize].as_ref();
+    let amount = ((n - 1) / n_symbols) + 1;
+    repeated(symbol, amount)
+}
+
+/// Repeat a text the given number of times.
+///
+/// To avoid excessively long output, more than twenty repetitions are written
+/// as the text followed by the count (e.g. "*×21").
+fn repeated(text: &str, amount: u64) -> EcoString {
+    const MAX_REPETITIONS: u64 = 20;
+
+    if amount > MAX_REPETITIONS {
+        eco_format!("{text}×{amount}")
+    } else {
+        text.repeat(amount as usize).into()
+    }
+}
+
+/// Stringify an integer to tally marks.
+///
+/// Every five marks are grouped into a bundle. To avoid excessively long
+/// output, the bundles are written as a multiple (e.g. "𝍸×21") for numbers of
+/// 105 and above.
+fn tally_marks(n: u64) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+
+    let (bundles, marks) = (n / 5, n % 5);
+    let mut fmt = repeated("𝍸", bundles);
+    fmt.push_str(&repeated("𝍷", marks));
+    fmt
+}
+
+/// Stringify an integer to counting rod numerals.
+///
+/// The ones, hundreds, ten thousands, etc. use the vertical unit forms, while
+/// the tens, thousands, etc. use the horizontal tens forms. Zero digits are
+/// written as `〇`.
+fn counting_rods(mut n: u64) -> EcoString {
+    if n == 0 {
+        return '〇'.into();
+    }
+
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push((n % 10) as u32);
+        n /= 10;
+    }
+
+    let mut fmt = EcoString::new();
+    for (place, &digit) in digits.iter().enumerate().rev() {
+        let start = if place % 2 == 0 { 0x1D360 } else { 0x1D369 };
+        fmt.push(match digit {
+            0 => '〇',
+            _ => char::from_u32(start + digit - 1).unwrap(),
+        });
+    }
+    fmt
+}
+
+/// Stringify an integer to Suzhou numerals.
+///
+/// The vertical forms of one, two, and three are easily confused when they
+/// follow each other. Therefore, every other digit in a run of them is written
+/// using the horizontal forms (一, 二, 三).
+fn suzhou_numeral(n: u64) -> EcoString {
+    const DIGITS: [char; 10] =
+        ['〇', '〡', '〢', '〣', '〤', '〥', '〦', '〧', '〨', '〩'];
+    const HORIZONTAL: [char; 3] = ['一', '二', '三'];
+
+    let mut fmt = EcoString::new();
+    let mut vertical = false;
+    for c in eco_format!("{n}").chars() {
+        let digit = c.to_digit(10).unwrap() as usize;
+        if (1..=3).contains(&digit) && vertical {
+            fmt.push(HORIZONTAL[digit - 1]);
+            vertical = false;
+        } else {
+            fmt.push(DIGITS[digit]);
+            vertical = (1..=3).contains(&digit);
+        }
+    }
+    fmt
+}
+
+/// Stringify an integer to die faces.
+///
+/// Numbers larger than six are decomposed greedily: as many sixes as possible
+/// are written, followed by a single die for the remainder (e.g. 8 is ⚅⚁).
+fn die_faces(n: u64) -> EcoString {
+    const FACES: [char; 6] = ['⚀', '⚁', '⚂', '⚃', '⚄', '⚅'];
+
+    if n == 0 {
+        return '-'.into();
+    }
+
+    let (sixes, rest) = (n / 6, n % 6);
+    let mut fmt = repeated("⚅", sixes);
+    if rest > 0 {
+        fmt.push(FACES[rest as usize - 1]);
+    }
+    fmt
+}
+
+/// Stringify an integer to a Braille number.
+///
+/// The number sign (⠼) is written once, followed by one cell per decimal
+/// digit.
+fn braille_numeral(n: u64) -> EcoString {
+    const DIGITS: [char; 10] = ['⠚', '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊'];
+
+    let mut fmt = EcoString::from('⠼');
+    for c in eco_format!("{n}").chars() {
+        fmt.push(DIGITS[c.to_digit(10).unwrap() as usize]);
+    }
+    fmt
+}
+
+/// Stringify an integer to an English ordinal with a suffix (1st, 2nd, etc.).
+///
+/// Numbers ending in 11, 12, and 13 always take the suffix "th".
+fn english_ordinal(n: u64) -> EcoString {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    eco_format!("{n}{suffix}")
+}
+
+/// Spell out an integer in English words.
+///
+/// Tens and ones are joined with a hyphen (twenty-three). With `Case::Upper`,
+/// every word is capitalized (Twenty-Three).
+fn english_cardinal(mut n: u64, case: Case) -> EcoString {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty",
+        "ninety",
+    ];
+    const SCALES: [&str; 7] =
+        ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion"];
+
+    if n == 0 {
+        return capitalize_words(ONES[0], case);
+    }
+
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push((n % 1000) as usize);
+        n /= 1000;
+    }
+
+    let mut words: Vec<EcoString> = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+
+        let (hundreds, rest) = (group / 100, group % 100);
+        if hundreds > 0 {
+            words.push(ONES[hundreds].into());
+            words.push("hundred".into());
+        }
+
+        if rest >= 20 && rest % 10 != 0 {
+            words.push(eco_format!("{}-{}", TENS[rest / 10], ONES[rest % 10]));
+        } else if rest >= 20 {
+            words.push(TENS[rest / 10].into());
+        } else if rest > 0 {
+            words.push(ONES[rest].into());
+        }
+
+        if scale > 0 {
+            words.push(SCALES[scale].into());
+        }
+    }
+
+    capitalize_words(&words.join(" "), case)
+}
+
+/// Spell out an integer as an English ordinal in words.
+///
+/// Only the last word takes the ordinal form (twenty-first, one hundred
+/// second).
+fn english_ordinal_word(n: u64, case: Case) -> EcoString {
+    let cardinal = english_cardinal(n, Case::Lower);
+    let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(split);
+    let last = match last {
+        "one" => "first".into(),
+        "two" => "second".into(),
+        "three" => "third".into(),
+        "five" => "fifth".into(),
+        "eight" => "eighth".into(),
+        "nine" => "ninth".into(),
+        "twelve" => "twelfth".into(),
+        _ => match last.strip_suffix('y') {
+            Some(stem) => eco_format!("{stem}ieth"),
+            None => eco_format!("{last}th"),
+        },
+    };
+    capitalize_words(&eco_format!("{head}{last}"), case)
+}
+
+/// Capitalize every word of the given text if the case is `Case::Upper`.
+/// Words are delimited by spaces and hyphens.
+fn capitalize_words(text: &str, case: Case) -> EcoString {
+    match case {
+        Case::Lower => text.into(),
+        Case::Upper => {
+            let mut fmt = EcoString::new();
+            let mut start = true;
+            for c in text.chars() {
+                if start {
+                    fmt.extend(c.to_uppercase());
+                } else {
+                    fmt.push(c);
+                }
+                start = c == ' ' || c == '-';
+            }
+            fmt
+        }
+    }
+}
+
+/// Spells out numbers in the words of a specific language.
+trait SpellOut {
+    /// Spell out the given number as a cardinal.
+    fn cardinal(&self, n: u64) -> EcoString;
+}
+
+/// Select the number words for the given language, defaulting to English.
+fn spell_out(lang: Lang) -> &'static dyn SpellOut {
+    match lang {
+        Lang::GERMAN => &German,
+        Lang::FRENCH => &French,
+        Lang::SPANISH => &Spanish,
+        Lang::UKRAINIAN => &Ukrainian,
+        _ => &English,
+    }
+}
+
+/// English number words.
+struct English;
+
+impl SpellOut for English {
+    fn cardinal(&self, n: u64) -> EcoString {
+        english_cardinal(n, Case::Lower)
+    }
+}
+
+/// German number words.
+///
+/// Numbers below one million are written as a single word, while
+/// "Million" and "Milliarde" are separate words.
+struct German;
+
+impl German {
+    const ONES: [&str; 20] = [
+        "null",
+        "eins",
+        "zwei",
+        "drei",
+        "vier",
+        "fünf",
+        "sechs",
+        "sieben",
+        "acht",
+        "neun",
+        "zehn",
+        "elf",
+        "zwölf",
+        "dreizehn",
+        "vierzehn",
+        "fünfzehn",
+        "sechzehn",
+        "siebzehn",
+        "achtzehn",
+        "neunzehn",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig",
+        "achtzig", "neunzig",
+    ];
+
+    /// Spell out a number below one thousand. Unless the number stands at
+    /// the very end, a trailing one is written as "ein".
+    fn below_thousand(n: u64, last: bool) -> EcoString {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut fmt = EcoString::new();
+        if hundreds > 0 {
+            fmt.push_str(&Self::below_hundred(hundreds, false));
+            fmt.push_str("hundert");
+        }
+        if rest > 0 {
+            fmt.push_str(&Self::below_hundred(rest, last));
+        }
+        fmt
+    }
+
+    fn below_hundred(n: u64, last: bool) -> EcoString {
+        let (tens, ones) = (n / 10, n % 10);
+        match n {
+            1 if !last => "ein".into(),
+            0..=19 => Self::ONES[n as usize].into(),
+            _ if ones == 0 => Self::TENS[tens as usize].into(),
+            _ => {
+                let ones = if ones == 1 { "ein" } else { Self::ONES[ones as usize] };
+                eco_format!("{ones}und{}", Self::TENS[tens as usize])
+            }
+        }
+    }
+}
+
+impl SpellOut for German {
+    fn cardinal(&self, n: u64) -> EcoString {
+        let Some(groups) = digit_groups(n) else { return eco_format!("{n}") };
+        if n == 0 {
+            return Self::ONES[0].into();
+        }
+
+        let mut words = Vec::new();
+        for (count, one, many) in
+            [(groups[3], "Milliarde", "Milliarden"), (groups[2], "Million", "Millionen")]
+        {
+            if count > 0 {
+                let mut fmt = Self::below_thousand(count, false);
+                if count % 100 == 1 {
+                    fmt.push('e');
+                }
+                words.push(eco_format!("{fmt} {}", if count == 1 { one } else { many }));
+            }
+        }
+
+        let mut fmt = EcoString::new();
+        if groups[1] > 0 {
+            fmt.push_str(&Self::below_thousand(groups[1], false));
+            fmt.push_str("tausend");
+        }
+        if groups[0] > 0 {
+            fmt.push_str(&Self::below_thousand(groups[0], true));
+        }
+        if !fmt.is_empty() {
+            words.push(fmt);
+        }
+
+        words.join(" ").into()
+    }
+}
+
+/// French number words.
+///
+/// Follows the traditional spelling, where only the tens and ones are joined
+/// with hyphens (deux cent vingt-trois) and "et" is inserted before un and
+/// onze (vingt et un).
+struct French;
+
+impl French {
+    const ONES: [&str; 17] = [
+        "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize",
+    ];
+    const TENS: [&str; 7] =
+        ["", "", "vingt", "trente", "quarante", "cinquante", "soixante"];
+
+    /// Spell out a number below one thousand. The plural forms "cents" and
+    /// "quatre-vingts" are only used if `plural` is set, i.e. when the number
+    /// is not followed by "mille".
+    fn below_thousand(n: u64, plural: bool) -> EcoString {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut words = Vec::new();
+        match hundreds {
+            0 => {}
+            1 => words.push("cent".into()),
+            _ if rest == 0 && plural => {
+                words.push(eco_format!("{} cents", Self::ONES[hundreds as usize]))
+            }
+            _ => words.push(eco_format!("{} cent", Self::ONES[hundreds as usize])),
+        }
+        if rest > 0 {
+            words.push(Self::below_hundred(rest, plural));
+        }
+        words.join(" ").into()
+    }
+
+    fn below_hundred(n: u64, plural: bool) -> EcoString {
+        let (tens, ones) = (n / 10, n % 10);
+        match (tens, ones) {
+            _ if n < 17 => Self::ONES[n as usize].into(),
+            (1, _) => eco_format!("dix-{}", Self::ONES[ones as usize]),
+            (2..=6, 0) => Self::TENS[tens as usize].into(),
+            (2..=6, 1) => eco_format!("{} et un", Self::TENS[tens as usize]),
+            (2..=6, _) => {
+                eco_format!("{}-{}", Self::TENS[tens as usize], Self::ONES[ones as usize])
+            }
+            (7, 1) => "soixante et onze".into(),
+            (7, _) => eco_format!("soixante-{}", Self::below_hundred(10 + ones, plural)),
+            (8, 0) if plural => "quatre-vingts".into(),
+            (8, 0) => "quatre-vingt".into(),
+            (8, _) => eco_format!("quatre-vingt-{}", Self::ONES[ones as usize]),
+            _ => eco_format!("quatre-vingt-{}", Self::below_hundred(10 + ones, plural)),
+        }
+    }
+}
+
+impl SpellOut for French {
+    fn cardinal(&self, n: u64) -> EcoString {
+        let Some(groups) = digit_groups(n) else { return eco_format!("{n}") };
+        if n == 0 {
+            return Self::ONES[0].into();
+        }
+
+        let mut words = Vec::new();
+        for (count, one, many) in
+            [(groups[3], "milliard", "milliards"), (groups[2], "million", "millions")]
+        {
+            if count > 0 {
+                let name = if count == 1 { one } else { many };
+                words.push(eco_format!("{} {name}", Self::below_thousand(count, true)));
+            }
+        }
+        match groups[1] {
+            0 => {}
+            1 => words.push("mille".into()),
+            count => {
+                words.push(eco_format!("{} mille", Self::below_thousand(count, false)))
+            }
+        }
+        if groups[0] > 0 {
+            words.push(Self::below_thousand(groups[0], true));
+        }
+
+        words.join(" ").into()
+    }
+}
+
+/// Spanish number words.
+///
+/// One thousand million is written as "mil millones" since "billón" denotes
+/// a million millions.
+struct Spanish;
+
+impl Spanish {
+    const ONES: [&str; 30] = [
+        "cero",
+        "uno",
+        "dos",
+        "tres",
+        "cuatro",
+        "cinco",
+        "seis",
+        "siete",
+        "ocho",
+        "nueve",
+        "diez",
+        "once",
+        "doce",
+        "trece",
+        "catorce",
+        "quince",
+        "dieciséis",
+        "diecisiete",
+        "dieciocho",
+        "diecinueve",
+        "veinte",
+        "veintiuno",
+        "veintidós",
+        "veintitrés",
+        "veinticuatro",
+        "veinticinco",
+        "veintiséis",
+        "veintisiete",
+        "veintiocho",
+        "veintinueve",
+    ];
+    const TENS: [&str; 10] = [
+        "",
+        "",
+        "",
+        "treinta",
+        "cuarenta",
+        "cincuenta",
+        "sesenta",
+        "setenta",
+        "ochenta",
+        "noventa",
+    ];
+    const HUNDREDS: [&str; 10] = [
+        "",
+        "ciento",
+        "doscientos",
+        "trescientos",
+        "cuatrocientos",
+        "quinientos",
+        "seiscientos",
+        "setecientos",
+        "ochocientos",
+        "novecientos",
+    ];
+
+    /// Spell out a number below one million. If `apocope` is set, a trailing
+    /// "uno" is shortened for use in front of a noun (veintiún mil).
+    fn below_million(n: u64, apocope: bool) -> EcoString {
+        let (thousands, rest) = (n / 1000, n % 1000);
+        let mut words = Vec::new();
+        match thousands {
+            0 => {}
+            1 => words.push("mil".into()),
+            _ => words.push(eco_format!("{} mil", Self::below_thousand(thousands, true))),
+        }
+        if rest > 0 {
+            words.push(Self::below_thousand(rest, apocope));
+        }
+        words.join(" ").into()
+    }
+
+    fn below_thousand(n: u64, apocope: bool) -> EcoString {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut words: Vec<EcoString> = Vec::new();
+        match hundreds {
+            0 => {}
+            1 if rest == 0 => words.push("cien".into()),
+            _ => words.push(Self::HUNDREDS[hundreds as usize].into()),
+        }
+
+        let (tens, ones) = (rest / 10, rest % 10);
+        let last = match rest {
+            0 => return words.join(" ").into(),
+            0..=29 => Self::ONES[rest as usize].into(),
+            _ if ones == 0 => Self::TENS[tens as usize].into(),
+            _ => eco_format!(
+                "{} y {}",
+                Self::TENS[tens as usize],
+                Self::ONES[ones as usize]
+            ),
+        };
+        words.push(match last.strip_suffix("uno") {
+            Some(stem) if apocope && stem.is_empty() => "un".into(),
+            Some(stem) if apocope && stem.ends_with('i') => eco_format!("{stem}ún"),
+            Some(stem) if apocope => eco_format!("{stem}un"),
+            _ => last,
+        });
+        words.join(" ").into()
+    }
+}
+
+impl SpellOut for Spanish {
+    fn cardinal(&self, n: u64) -> EcoString {
+        let Some(groups) = digit_groups(n) else { return eco_format!("{n}") };
+        if n == 0 {
+            return Self::ONES[0].into();
+        }
+
+        let mut words = Vec::new();
+        match groups[3] * 1000 + groups[2] {
+            0 => {}
+            1 => words.push("un millón".into()),
+            millions => words
+                .push(eco_format!("{} millones", Self::below_million(millions, true))),
+        }
+        let rest = groups[1] * 1000 + groups[0];
+        if rest > 0 {
+            words.push(Self::below_million(rest, false));
         }
+
+        words.join(" ").into()
     }
 }
 
-/// Stringify an integer to a Hebrew number.
-fn hebrew_numeral(mut n: u64) -> EcoString {
-    if n == 0 {
-        return '-'.into();
+/// Ukrainian number words.
+///
+/// The nouns for thousands, millions, and billions are inflected according to
+/// the preceding number (дві тисячі, пʼять тисяч).
+struct Ukrainian;
+
+impl Ukrainian {
+    const ONES: [&str; 20] = [
+        "нуль",
+        "один",
+        "два",
+        "три",
+        "чотири",
+        "пʼять",
+        "шість",
+        "сім",
+        "вісім",
+        "девʼять",
+        "десять",
+        "одинадцять",
+        "дванадцять",
+        "тринадцять",
+        "чотирнадцять",
+        "пʼятнадцять",
+        "шістнадцять",
+        "сімнадцять",
+        "вісімнадцять",
+        "девʼятнадцять",
+    ];
+    const TENS: [&str; 10] = [
+        "",
+        "",
+        "двадцять",
+        "тридцять",
+        "сорок",
+        "пʼятдесят",
+        "шістдесят",
+        "сімдесят",
+        "вісімдесят",
+        "девʼяносто",
+    ];
+    const HUNDREDS: [&str; 10] = [
+        "",
+        "сто",
+        "двісті",
+        "триста",
+        "чотириста",
+        "пʼятсот",
+        "шістсот",
+        "сімсот",
+        "вісімсот",
+        "девʼятсот",
+    ];
+
+    /// Spell out a number below one thousand. If `feminine` is set, the
+    /// feminine forms of one and two are used (одна, дві).
+    fn below_thousand(n: u64, feminine: bool) -> Vec<&'static str> {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let mut words = Vec::new();
+        if hundreds > 0 {
+            words.push(Self::HUNDREDS[hundreds as usize]);
+        }
+        if rest >= 20 {
+            words.push(Self::TENS[(rest / 10) as usize]);
+        }
+        let ones = if rest >= 20 { rest % 10 } else { rest };
+        match ones {
+            0 => {}
+            1 if feminine => words.push("одна"),
+            2 if feminine => words.push("дві"),
+            _ => words.push(Self::ONES[ones as usize]),
+        }
+        words
     }
-    let mut fmt = EcoString::new();
-    'outer: for (name, value) in [
-        ('ת', 400),
-        ('ש', 300),
-        ('ר', 200),
-        ('ק', 100),
-        ('צ', 90),
-        ('פ', 80),
-        ('ע', 70),
-        ('ס', 60),
-        ('נ', 50),
-        ('מ', 40),
-        ('ל', 30),
-        ('כ', 20),
-        ('י', 10),
-        ('ט', 9),
-        ('ח', 8),
-        ('ז', 7),
-        ('ו', 6),
-        ('ה', 5),
-        ('ד', 4),
-        ('ג', 3),
-        ('ב', 2),
-        ('א', 1),
-    ] {
-        while n >= value {
-            match n {
-                15 => fmt.push_str("ט״ו"),
-                16 => fmt.push_str("ט״ז"),
-                _ => {
-                    let append_geresh = n == value && fmt.is_empty();
-                    if n == value && !fmt.is_empty() {
-                        fmt.push('״');
-                    }
-                    fmt.push(name);
-                    if append_geresh {
-                        fmt.push('׳');
-                    }
 
-                    n -= value;
-                    continue;
-                }
+    /// Select the grammatical form of a noun following the given number.
+    fn inflect<'a>(n: u64, [one, few, many]: [&'a str; 3]) -> &'a str {
+        match (n % 10, n % 100) {
+            (_, 11..=14) => many,
+            (1, _) => one,
+            (2..=4, _) => few,
+            _ => many,
+        }
+    }
+}
+
+impl SpellOut for Ukrainian {
+    fn cardinal(&self, n: u64) -> EcoString {
+        let Some(groups) = digit_groups(n) else { return eco_format!("{n}") };
+        if n == 0 {
+            return Self::ONES[0].into();
+        }
+
+        let mut words = Vec::new();
+        for (count, forms, feminine) in [
+            (groups[3], ["мільярд", "мільярди", "мільярдів"], false),
+            (groups[2], ["мільйон", "мільйони", "мільйонів"], false),
+            (groups[1], ["тисяча", "тисячі", "тисяч"], true),
+        ] {
+            if count > 0 {
+                words.extend(Self::below_thousand(count, feminine));
+                words.push(Self::inflect(count, forms));
             }
-            break 'outer;
         }
+        words.extend(Self::below_thousand(groups[0], false));
+
+        words.join(" ").into()
     }
-    fmt
+}
+
+/// Split a number below one trillion into groups of three digits, starting
+/// with the ones. Returns `None` for larger numbers.
+fn digit_groups(mut n: u64) -> Option<[u64; 4]> {
+    let mut groups = [0; 4];
+    for group in &mut groups {
+        *group = n % 1000;
+        n /= 1000;
+    }
+    (n == 0).then_some(groups)
 }
 
 /// Stringify an integer to a Roman numeral.
-fn roman_numeral(mut n: u64, case: Case) -> EcoString {
+///
+/// With the vinculum style, the thousands of numbers from 4000 on are written
+/// as a Roman numeral of their own with an overline on each letter. This
+/// recurses for higher magnitudes, adding another overline each time.
+fn roman_numeral(mut n: u64, case: Case, style: RomanStyle) -> EcoString {
     if n == 0 {
         return match case {
             Case::Lower => 'n'.into(),
@@ -573,17 +4202,25 @@ fn roman_numeral(mut n: u64, case: Case) -> EcoString {
         };
     }
 
+    let mut fmt = EcoString::new();
+    if n >= 4000 {
+        match style {
+            RomanStyle::Vinculum => {
+                for c in roman_numeral(n / 1000, Case::Upper, style).chars() {
+                    fmt.push(c);
+                    if c != '\u{0305}' {
+                        fmt.push('\u{0305}');
+                    }
+                }
+            }
+            RomanStyle::Plain => fmt.push_str(&repeated("M", n / 1000)),
+        }
+        n %= 1000;
+    }
+
     // Adapted from Yann Villessuzanne's roman.rs under the
     // Unlicense, at https://github.com/linfir/roman.rs/
-    let mut fmt = EcoString::new();
     for &(name, value) in &[
-        ("M̅", 1000000),
-        ("D̅", 500000),
-        ("C̅", 100000),
-        ("L̅", 50000),
-        ("X̅", 10000),
-        ("V̅", 5000),
-        ("I̅V̅", 4000),
         ("M", 1000),
         ("CM", 900),
         ("D", 500),
@@ -600,15 +4237,49 @@ fn roman_numeral(mut n: u64, case: Case) -> EcoString {
     ] {
         while n >= value {
             n -= value;
-            for c in name.chars() {
-                match case {
-                    Case::Lower => fmt.extend(c.to_lowercase()),
-                    Case::Upper => fmt.push(c),
-                }
-            }
+            fmt.push_str(name);
         }
     }
 
+    case.apply(&fmt).into()
+}
+
+/// Stringify an integer to a Roman numeral using the dedicated Unicode
+/// codepoints.
+///
+/// The precomposed numerals (Ⅰ to Ⅻ) are used for numbers up to twelve and for
+/// the final digit of larger numbers, while the remaining digits are composed
+/// from the single letter numerals (Ⅹ, Ⅼ, Ⅽ, etc.).
+fn roman_numeral_unicode(n: u64, case: Case) -> EcoString {
+    let offset = match case {
+        Case::Lower => 0x10,
+        Case::Upper => 0,
+    };
+    let precomposed = |n: u64| char::from_u32(0x2160 + offset + n as u32 - 1).unwrap();
+
+    if (1..=12).contains(&n) {
+        return precomposed(n).into();
+    }
+
+    let mut fmt: EcoString = roman_numeral(n - n % 10, Case::Upper, RomanStyle::Vinculum)
+        .chars()
+        .map(|c| {
+            let letter = match c {
+                'I' => 0x2160,
+                'V' => 0x2164,
+                'X' => 0x2169,
+                'L' => 0x216C,
+                'C' => 0x216D,
+                'D' => 0x216E,
+                'M' => 0x216F,
+                _ => return case.apply(&c.to_string()).chars().next().unwrap(),
+            };
+            char::from_u32(letter + offset).unwrap()
+        })
+        .collect();
+    if n % 10 != 0 {
+        fmt.push(precomposed(n % 10));
+    }
     fmt
 }
 
@@ -619,9 +4290,12 @@ fn roman_numeral(mut n: u64, case: Case) -> EcoString {
 /// [The Greek Number Converter][convert] and also described in
 /// [Greek Numbers][numbers].
 ///
+/// Without the keraia (ʹ), the groups of myriads are separated by spaces
+/// instead of commas.
+///
 /// [converter]: https://www.russellcottrell.com/greek/utilities/GreekNumberConverter.htm
 /// [numbers]: https://mathshistory.st-andrews.ac.uk/HistTopics/Greek_numbers/
-fn greek_numeral(n: u64, case: Case) -> EcoString {
+fn greek_numeral(n: u64, case: Case, keraia: bool) -> EcoString {
     let thousands = [
         ["͵α", "͵Α"],
         ["͵β", "͵Β"],
@@ -721,12 +4395,12 @@ fn greek_numeral(n: u64, case: Case) -> EcoString {
         }
 
         if previous_has_number {
-            fmt.push_str(", ");
+            fmt.push_str(if keraia { ", " } else { " " });
         }
 
         if let Some(m_prefix) = get_m_prefix(m_power) {
             fmt.push_str(m_prefix);
-            fmt.push_str("Μ");
+            fmt.push_str(["μ", "Μ"][case]);
         }
         if th != 0 {
             let thousand_digit = thousands[th - 1][case];
@@ -745,7 +4419,7 @@ fn greek_numeral(n: u64, case: Case) -> EcoString {
             fmt.push_str(one_digit);
         }
         // if we do not have thousan, we need to append 'ʹ' at the end.
-        if th == 0 {
+        if th == 0 && keraia {
             fmt.push_str("ʹ");
         }
         previous_has_number = true;
@@ -753,6 +4427,142 @@ fn greek_numeral(n: u64, case: Case) -> EcoString {
     fmt
 }
 
+/// Read the value of a Roman numeral, accepting lower- and uppercase letters.
+fn decode_roman(text: &str) -> Option<u64> {
+    if text.eq_ignore_ascii_case("n") {
+        return Some(0);
+    }
+
+    let mut n: i64 = 0;
+    let mut values = text
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'I' => Some(1),
+            'V' => Some(5),
+            'X' => Some(10),
+            'L' => Some(50),
+            'C' => Some(100),
+            'D' => Some(500),
+            'M' => Some(1000),
+            _ => None,
+        })
+        .collect::<Option<Vec<i64>>>()?
+        .into_iter()
+        .peekable();
+    while let Some(value) = values.next() {
+        if values.peek().is_some_and(|&next| next > value) {
+            n -= value;
+        } else {
+            n += value;
+        }
+    }
+    u64::try_from(n).ok().filter(|&n| n > 0)
+}
+
+/// Read the value of a Greek numeral without myriads, accepting lower- and
+/// uppercase letters.
+fn decode_greek(text: &str) -> Option<u64> {
+    if text == "𐆊" {
+        return Some(0);
+    }
+
+    let text = text.strip_suffix('ʹ').unwrap_or(text);
+    let mut n = 0;
+    let mut thousands = false;
+    for c in text.chars() {
+        if c == '͵' {
+            thousands = true;
+            continue;
+        }
+
+        let value = match c {
+            'α' | 'Α' => 1,
+            'β' | 'Β' => 2,
+            'γ' | 'Γ' => 3,
+            'δ' | 'Δ' => 4,
+            'ε' | 'Ε' => 5,
+            'ϛ' | 'Ϛ' => 6,
+            'ζ' | 'Ζ' => 7,
+            'η' | 'Η' => 8,
+            'θ' | 'Θ' => 9,
+            'ι' | 'Ι' => 10,
+            'κ' | 'Κ' => 20,
+            'λ' | 'Λ' => 30,
+            'μ' | 'Μ' => 40,
+            'ν' | 'Ν' => 50,
+            'ξ' | 'Ξ' => 60,
+            'ο' | 'Ο' => 70,
+            'π' | 'Π' => 80,
+            'ϙ' | 'Ϟ' => 90,
+            'ρ' | 'Ρ' => 100,
+            'σ' | 'Σ' => 200,
+            'τ' | 'Τ' => 300,
+            'υ' | 'Υ' => 400,
+            'φ' | 'Φ' => 500,
+            'χ' | 'Χ' => 600,
+            'ψ' | 'Ψ' => 700,
+            'ω' | 'Ω' => 800,
+            'ϡ' | 'Ϡ' => 900,
+            _ => return None,
+        };
+
+        if thousands {
+            if value >= 10 {
+                return None;
+            }
+            n += value * 1000;
+            thousands = false;
+        } else {
+            n += value;
+        }
+    }
+    (!text.is_empty() && !thousands).then_some(n)
+}
+
+/// Read the value of a Hebrew numeral.
+///
+/// A geresh that is followed by further letters or by the word for thousand
+/// or thousands separates the thousands. Otherwise, it marks a unit.
+fn decode_hebrew(text: &str) -> Option<u64> {
+    let letters = |text: &str| {
+        text.chars()
+            .filter(|&c| c != '׳' && c != '״')
+            .map(|c| {
+                HEBREW_NUMERALS.iter().find(|&&(name, _)| name == c).map(|&(_, v)| v)
+            })
+            .sum::<Option<u64>>()
+            .filter(|_| !text.is_empty())
+    };
+
+    if let Some(thousands) =
+        text.strip_suffix(" אלף").or_else(|| text.strip_suffix(" אלפים"))
+    {
+        return letters(thousands.strip_suffix('׳')?)?.checked_mul(1000);
+    }
+
+    match text.split_once('׳') {
+        Some((thousands, rest)) if !rest.is_empty() => {
+            letters(thousands)?.checked_mul(1000)?.checked_add(letters(rest)?)
+        }
+        _ => letters(text),
+    }
+}
+
+/// Read a number written with [`zeroless`] in an alphabet of contiguous
+/// characters.
+fn decode_zeroless(text: &str, alphabet: RangeInclusive<char>) -> Option<u64> {
+    let base = alphabet.clone().count() as u64;
+    let mut n: u64 = 0;
+    for c in text.chars() {
+        if !alphabet.contains(&c) {
+            return None;
+        }
+        let digit = (c as u64) - (*alphabet.start() as u64) + 1;
+        n = n.checked_mul(base)?.checked_add(digit)?;
+    }
+    (!text.is_empty()).then_some(n)
+}
+
 /// Stringify a number using a base-N counting system with no zero digit.
 ///
 /// This is best explained by example. Suppose our digits are 'A', 'B', and 'C'.
@@ -791,6 +4601,78 @@ fn zeroless<const N_DIGITS: usize>(alphabet: [char; N_DIGITS], mut n: u64) -> Ec
     cs.chars().rev().collect()
 }
 
+/// Stringify a number using a decimal additive system.
+///
+/// Each nonzero digit is written with its own symbol for its power of ten,
+/// where `symbols[k][d - 1]` represents the digit `d` in place `k`. Zero digits
+/// are omitted. Returns `None` if the number has a digit for which there is
+/// no symbol.
+fn additive(symbols: &[&[char]], mut n: u64) -> Option<EcoString> {
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push((n % 10) as usize);
+        n /= 10;
+    }
+
+    let mut fmt = EcoString::new();
+    for (place, &digit) in digits.iter().enumerate().rev() {
+        if digit != 0 {
+            fmt.push(*symbols.get(place)?.get(digit - 1)?);
+        }
+    }
+    Some(fmt)
+}
+
+/// Stringify a number using a base-N counting system with no zero digit, where
+/// the digits may consist of multiple characters.
+///
+/// This works like [`zeroless`], but puts the `separator` between consecutive
+/// digits to keep them apart.
+fn zeroless_str<const N_DIGITS: usize>(
+    alphabet: [&str; N_DIGITS],
+    separator: &str,
+    mut n: u64,
+) -> EcoString {
+    if n == 0 {
+        return '-'.into();
+    }
+    let n_digits = N_DIGITS as u64;
+    let mut digits = Vec::new();
+    while n > 0 {
+        n -= 1;
+        digits.push(alphabet[(n % n_digits) as usize]);
+        n /= n_digits;
+    }
+
+    let mut fmt = EcoString::new();
+    for (i, digit) in digits.iter().rev().enumerate() {
+        if i > 0 {
+            fmt.push_str(separator);
+        }
+        fmt.push_str(digit);
+    }
+    fmt
+}
+
+/// Stringify a number using a base-N counting system with a zero digit.
+///
+/// The first digit of the alphabet represents zero.
+fn positional<const N_DIGITS: usize>(
+    alphabet: [char; N_DIGITS],
+    mut n: u64,
+) -> EcoString {
+    let n_digits = N_DIGITS as u64;
+    let mut cs = EcoString::new();
+    loop {
+        cs.push(alphabet[(n % n_digits) as usize]);
+        n /= n_digits;
+        if n == 0 {
+            break;
+        }
+    }
+    cs.chars().rev().collect()
+}
+
 /// Stringify a number using a base-10 counting system with a zero digit.
 ///
 /// This function assumes that the digits occupy contiguous codepoints.
@@ -805,3 +4687,736 @@ fn decimal(start: char, mut n: u64) -> EcoString {
     }
     cs.chars().rev().collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbering_kind_char_roundtrip() {
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            if let Some(kind) = NumberingKind::from_char(c) {
+                assert_eq!(kind.to_char(), c, "{kind:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cyrillic_numeral() {
+        for (n, expected) in [
+            (1, "а҃"),
+            (11, "а҃і"),
+            (15, "є҃і"),
+            (21, "к҃а"),
+            (444, "ум҃д"),
+            (1000, "҂а҃"),
+            (2024, "҂вк҃д"),
+            (10000, "10000"),
+        ] {
+            assert_eq!(NumberingKind::CyrillicNumeral.apply(n), expected);
+        }
+    }
+
+    #[test]
+    fn test_hebrew_letter() {
+        for (n, expected) in
+            [(0, "-"), (1, "א"), (10, "י"), (15, "ס"), (16, "ע"), (22, "ת"), (23, "אא")]
+        {
+            assert_eq!(NumberingKind::HebrewLetter.apply(n), expected);
+        }
+    }
+
+    #[test]
+    fn test_greek_letter() {
+        for (n, lower, upper) in [
+            (0, "-", "-"),
+            (1, "α", "Α"),
+            (6, "ζ", "Ζ"),
+            (24, "ω", "Ω"),
+            (25, "αα", "ΑΑ"),
+            (26, "αβ", "ΑΒ"),
+        ] {
+            assert_eq!(NumberingKind::LowerGreekLetter.apply(n), lower);
+            assert_eq!(NumberingKind::UpperGreekLetter.apply(n), upper);
+        }
+    }
+
+    #[test]
+    fn test_japanese_kanji() {
+        for (n, expected) in [
+            (0, "〇"),
+            (1, "一"),
+            (10, "十"),
+            (20, "二十"),
+            (105, "百五"),
+            (111, "百十一"),
+            (1000, "千"),
+            (2024, "二千二十四"),
+            (10000, "一万"),
+            (100000000, "一億"),
+        ] {
+            assert_eq!(NumberingKind::LowerJapaneseKanji.apply(n), expected);
+        }
+    }
+
+    #[test]
+    fn test_english_ordinal() {
+        let expected = [
+            "1st", "2nd", "3rd", "4th", "5th", "6th", "7th", "8th", "9th", "10th",
+            "11th", "12th", "13th", "14th", "15th", "16th", "17th", "18th", "19th",
+            "20th", "21st", "22nd", "23rd", "24th", "25th",
+        ];
+        for (n, expected) in (1..=25).zip(expected) {
+            assert_eq!(NumberingKind::EnglishOrdinal.apply(n), expected);
+        }
+
+        let expected = [
+            "101st", "102nd", "103rd", "104th", "105th", "106th", "107th", "108th",
+            "109th", "110th", "111th", "112th", "113th",
+        ];
+        for (n, expected) in (101..=113).zip(expected) {
+            assert_eq!(NumberingKind::EnglishOrdinal.apply(n), expected);
+        }
+    }
+
+    #[test]
+    fn test_english_cardinal() {
+        for (n, lower, upper) in [
+            (0, "zero", "Zero"),
+            (1, "one", "One"),
+            (13, "thirteen", "Thirteen"),
+            (20, "twenty", "Twenty"),
+            (23, "twenty-three", "Twenty-Three"),
+            (100, "one hundred", "One Hundred"),
+            (105, "one hundred five", "One Hundred Five"),
+            (999, "nine hundred ninety-nine", "Nine Hundred Ninety-Nine"),
+            (1000, "one thousand", "One Thousand"),
+            (1_000_001, "one million one", "One Million One"),
+            (
+                2_345_678,
+                "two million three hundred forty-five thousand six hundred seventy-eight",
+                "Two Million Three Hundred Forty-Five Thousand Six Hundred Seventy-Eight",
+            ),
+        ] {
+            assert_eq!(NumberingKind::LowerEnglishCardinal.apply(n), lower);
+            assert_eq!(NumberingKind::UpperEnglishCardinal.apply(n), upper);
+        }
+    }
+
+    #[test]
+    fn test_english_ordinal_word() {
+        let expected = [
+            "first",
+            "second",
+            "third",
+            "fourth",
+            "fifth",
+            "sixth",
+            "seventh",
+            "eighth",
+            "ninth",
+            "tenth",
+            "eleventh",
+            "twelfth",
+            "thirteenth",
+        ];
+        for (n, expected) in (1..=13).zip(expected) {
+            assert_eq!(NumberingKind::LowerEnglishOrdinalWord.apply(n), expected);
+        }
+
+        for (n, lower, upper) in [
+            (20, "twentieth", "Twentieth"),
+            (21, "twenty-first", "Twenty-First"),
+            (22, "twenty-second", "Twenty-Second"),
+            (23, "twenty-third", "Twenty-Third"),
+            (100, "one hundredth", "One Hundredth"),
+            (101, "one hundred first", "One Hundred First"),
+            (102, "one hundred second", "One Hundred Second"),
+            (103, "one hundred third", "One Hundred Third"),
+            (1000, "one thousandth", "One Thousandth"),
+        ] {
+            assert_eq!(NumberingKind::LowerEnglishOrdinalWord.apply(n), lower);
+            assert_eq!(NumberingKind::UpperEnglishOrdinalWord.apply(n), upper);
+        }
+    }
+
+    #[test]
+    fn test_words() {
+        let words = |lang, n| {
+            let locale = NumberingLocale { lang, region: None };
+            NumberingKind::Words.apply_localized(n, Some(locale))
+        };
+
+        assert_eq!(NumberingKind::Words.apply(21), "twenty-one");
+        assert_eq!(words(Lang::ITALIAN, 21), "twenty-one");
+
+        for (n, en, de, fr, es, uk) in [
+            (0, "zero", "null", "zéro", "cero", "нуль"),
+            (1, "one", "eins", "un", "uno", "один"),
+            (17, "seventeen", "siebzehn", "dix-sept", "diecisiete", "сімнадцять"),
+            (
+                21,
+                "twenty-one",
+                "einundzwanzig",
+                "vingt et un",
+                "veintiuno",
+                "двадцять один",
+            ),
+            (
+                71,
+                "seventy-one",
+                "einundsiebzig",
+                "soixante et onze",
+                "setenta y uno",
+                "сімдесят один",
+            ),
+            (80, "eighty", "achtzig", "quatre-vingts", "ochenta", "вісімдесят"),
+            (100, "one hundred", "einhundert", "cent", "cien", "сто"),
+            (
+                201,
+                "two hundred one",
+                "zweihunderteins",
+                "deux cent un",
+                "doscientos uno",
+                "двісті один",
+            ),
+            (2000, "two thousand", "zweitausend", "deux mille", "dos mil", "дві тисячі"),
+            (
+                21_000,
+                "twenty-one thousand",
+                "einundzwanzigtausend",
+                "vingt et un mille",
+                "veintiún mil",
+                "двадцять одна тисяча",
+            ),
+            (
+                5_000_000,
+                "five million",
+                "fünf Millionen",
+                "cinq millions",
+                "cinco millones",
+                "пʼять мільйонів",
+            ),
+            (
+                1_000_000_000,
+                "one billion",
+                "eine Milliarde",
+                "un milliard",
+                "mil millones",
+                "один мільярд",
+            ),
+        ] {
+            assert_eq!(words(Lang::ENGLISH, n), en);
+            assert_eq!(words(Lang::GERMAN, n), de);
+            assert_eq!(words(Lang::FRENCH, n), fr);
+            assert_eq!(words(Lang::SPANISH, n), es);
+            assert_eq!(words(Lang::UKRAINIAN, n), uk);
+        }
+
+        assert_eq!(words(Lang::GERMAN, 1_000_000_000_000), "1000000000000");
+    }
+
+    #[test]
+    fn test_chinese_ordinal() {
+        for (n, lower, upper) in [
+            (1, "第一", "第壹"),
+            (2, "第二", "第贰"),
+            (10, "第十", "第拾"),
+            (12, "第十二", "第拾贰"),
+            (105, "第一百零五", "第壹佰零伍"),
+        ] {
+            assert_eq!(NumberingKind::LowerChineseOrdinal.apply(n), lower);
+            assert_eq!(NumberingKind::UpperChineseOrdinal.apply(n), upper);
+        }
+    }
+
+    #[test]
+    fn test_bopomofo_and_korean_jamo() {
+        assert_eq!(NumberingKind::from_char('ㄅ'), Some(NumberingKind::Bopomofo));
+        assert_eq!(NumberingKind::from_char('ㄩ'), None);
+        assert_eq!(NumberingKind::from_char('ㄱ'), Some(NumberingKind::KoreanJamo));
+        assert_eq!(NumberingKind::Bopomofo.apply(1), "ㄅ");
+        assert_eq!(NumberingKind::KoreanJamo.apply(1), "ㄱ");
+    }
+
+    #[test]
+    fn test_korean_hanja() {
+        for (n, lower, upper) in [
+            (0, "零", "零"),
+            (1, "一", "壹"),
+            (2, "二", "貳"),
+            (10, "十", "壹拾"),
+            (21, "二十一", "貳拾壹"),
+            (105, "百五", "壹佰伍"),
+            (3000, "三千", "參仟"),
+            (10000, "一萬", "壹萬"),
+            (10001, "一萬一", "壹萬壹"),
+            (12345, "一萬二千三百四十五", "壹萬貳仟參佰肆拾伍"),
+            (20000, "二萬", "貳萬"),
+            (100000000, "一億", "壹億"),
+        ] {
+            assert_eq!(NumberingKind::LowerKoreanHanja.apply(n), lower);
+            assert_eq!(NumberingKind::UpperKoreanHanja.apply(n), upper);
+        }
+    }
+
+    #[test]
+    fn test_korean_native() {
+        for (n, expected) in [
+            (0, "0"),
+            (1, "하나"),
+            (2, "둘"),
+            (10, "열"),
+            (11, "열하나"),
+            (20, "스물"),
+            (21, "스물하나"),
+            (99, "아흔아홉"),
+            (100, "100"),
+        ] {
+            assert_eq!(NumberingKind::KoreanNative.apply(n), expected);
+        }
+    }
+
+    #[test]
+    fn test_numbering_kind_name_roundtrip() {
+        for name in [
+            "cyrillic-numeral",
+            "hebrew-letter",
+            "greek-letter",
+            "Greek-letter",
+            "kanji",
+            "1st",
+            "one",
+            "One",
+            "first",
+            "First",
+            "words",
+            "第一",
+            "第壹",
+            "hanja",
+            "hanja-formal",
+            "하나",
+        ] {
+            let kind = NumberingKind::from_name(name).unwrap();
+            assert_eq!(kind.to_name(), Some(name));
+        }
+    }
+
+    #[test]
+    fn test_numbering_pattern_named() {
+        let pattern: NumberingPattern = "{kanji}.{1st})".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [
+                (EcoString::from(""), NumberingKind::LowerJapaneseKanji),
+                (EcoString::from("."), NumberingKind::EnglishOrdinal),
+            ]
+        );
+        assert_eq!(pattern.suffix, ")");
+
+        let pattern: NumberingPattern = "{1}".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [(EcoString::from("{"), NumberingKind::Arabic)]
+        );
+        assert_eq!(pattern.suffix, "}");
+
+        let pattern: NumberingPattern = "1.{xyz".parse().unwrap();
+        assert_eq!(pattern.suffix, ".{xyz");
+        let pattern: NumberingPattern = "{xy 1".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [(EcoString::from("{xy "), NumberingKind::Arabic)]
+        );
+
+        assert_eq!(
+            "{foo}".parse::<NumberingPattern>(),
+            Err(NumberingPatternError::new(0..5, "unknown counting symbol {foo}"))
+        );
+    }
+
+    #[test]
+    fn test_numbering_pattern_escape() {
+        let pattern: NumberingPattern = r"{'A'}\1{'a'}".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [(EcoString::from(r"A\"), NumberingKind::Arabic)]
+        );
+        assert_eq!(pattern.suffix, "a");
+        assert_eq!(pattern.apply(&[2]), r"A\2a");
+        assert_eq!(
+            "{'1'}".parse::<NumberingPattern>(),
+            Err(NumberingPatternError::new(0..5, "no counting symbol found"))
+        );
+
+        let pattern: NumberingPattern = "{'it''}}1{'x".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [(EcoString::from("it'}"), NumberingKind::Arabic)]
+        );
+        assert_eq!(pattern.suffix, "{'x");
+    }
+
+    #[test]
+    fn test_numbering_pattern_padding() {
+        let pattern: NumberingPattern = "(001.1)".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [
+                (EcoString::from("("), NumberingKind::Arabic),
+                (EcoString::from("."), NumberingKind::Arabic)
+            ]
+        );
+        assert_eq!(pattern.widths.as_slice(), [3, 1]);
+        assert_eq!(pattern.apply(&[7, 8]), "(007.8)");
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let Some(kind) = NumberingKind::from_char(c) else { continue };
+            if let Some(zero) = kind.zero_digit() {
+                assert_eq!(kind.apply(0), zero.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_numbering_negative() {
+        assert_eq!(NumberingKind::Arabic.apply(-1), "\u{2212}1");
+        assert_eq!(NumberingKind::Arabic.apply(0), "0");
+        assert_eq!(NumberingKind::Arabic.apply(i64::MIN), "\u{2212}9223372036854775808");
+        assert_eq!(
+            NumberingKind::EasternArabicPersian.apply(-5),
+            "\u{200E}\u{2212}\u{06F5}"
+        );
+        assert_eq!(NumberingKind::LowerLatin.apply(-27), "\u{2212}aa");
+    }
+
+    #[test]
+    fn test_chinese_variant() {
+        let locale = |lang: &str, region: Option<&str>| NumberingLocale {
+            lang: lang.parse().unwrap(),
+            region: region.map(|region| region.parse().unwrap()),
+        };
+        let upper = NumberingKind::UpperSimplifiedChinese;
+        assert_eq!(upper.apply(2), "贰");
+        assert_eq!(upper.apply_localized(2, Some(locale("zh", None))), "贰");
+        assert_eq!(upper.apply_localized(2, Some(locale("zh", Some("CN")))), "贰");
+        assert_eq!(upper.apply_localized(2, Some(locale("zh", Some("TW")))), "貳");
+        assert_eq!(upper.apply_localized(2, Some(locale("zh", Some("HK")))), "貳");
+        assert_eq!(upper.apply_localized(2, Some(locale("en", Some("HK")))), "贰");
+
+        let pattern: NumberingPattern = "(壹)".parse().unwrap();
+        let tw = Some(locale("zh", Some("TW")));
+        assert_eq!(pattern.apply_localized(&[2], tw), "(貳)");
+        assert_eq!(pattern.apply_kth_localized(1, 2, tw), "(貳)");
+        assert_eq!(pattern.apply_kth(1, 2), "(贰)");
+    }
+
+    #[test]
+    fn test_numbering_trimmed() {
+        let pattern: NumberingPattern = "(1.a)".parse().unwrap();
+        let untrimmed = Numbering::Pattern(pattern.clone());
+        let trimmed = untrimmed.clone().trimmed();
+        assert_ne!(untrimmed, trimmed);
+        assert_ne!(typst_utils::hash128(&untrimmed), typst_utils::hash128(&trimmed));
+        assert_eq!(untrimmed.clone().with_trim(NumberingTrim::default()), untrimmed);
+        assert_eq!(
+            untrimmed
+                .clone()
+                .with_trim(NumberingTrim { prefix: true, suffix: true }),
+            trimmed
+        );
+
+        let Numbering::Pattern(trimmed) = trimmed else { unreachable!() };
+        assert_eq!(pattern.apply(&[1, 2]), "(1.b)");
+        assert_eq!(trimmed.apply(&[1, 2]), "1.b");
+        assert_eq!(trimmed.apply(&[1, 2, 3]), "1.b.c");
+
+        let pattern: NumberingPattern = "§1.a)".parse().unwrap();
+        let trim = |prefix, suffix| {
+            let Numbering::Pattern(pattern) = Numbering::Pattern(pattern.clone())
+                .with_trim(NumberingTrim { prefix, suffix })
+            else {
+                unreachable!()
+            };
+            pattern.apply(&[1, 2, 3])
+        };
+        assert_eq!(trim(false, false), "§1.b.c)");
+        assert_eq!(trim(true, false), "1.b.c)");
+        assert_eq!(trim(false, true), "§1.b.c");
+        assert_eq!(trim(true, true), "1.b.c");
+    }
+
+    #[test]
+    fn test_numbering_pattern_piece_suffixes() {
+        let pattern: NumberingPattern = "1:{|}a.".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [
+                (EcoString::from(""), NumberingKind::Arabic),
+                (EcoString::from(""), NumberingKind::LowerLatin),
+            ]
+        );
+        assert_eq!(pattern.suffixes.as_slice(), [EcoString::from(":"), EcoString::new()]);
+        assert_eq!(pattern.suffix, ".");
+        assert_eq!(pattern.apply(&[1]), "1:");
+        assert_eq!(pattern.apply(&[1, 2]), "1:b.");
+        assert_eq!(pattern.apply(&[1, 2, 3]), "1:b.c.");
+        assert_eq!(pattern.apply_kth(0, 1), "1:");
+        assert_eq!(pattern.apply_kth(1, 1), "a.");
+
+        let pattern: NumberingPattern = "(I){|} – 1".parse().unwrap();
+        assert_eq!(pattern.apply(&[4]), "(IV)");
+        assert_eq!(pattern.apply(&[4, 2]), "(IV) – 2");
+        assert_eq!(pattern.apply(&[4, 2, 3]), "(IV) – 2 – 3");
+
+        let pattern: NumberingPattern = "|1|a|".parse().unwrap();
+        assert_eq!(pattern.suffixes.as_slice(), [EcoString::new(), EcoString::new()]);
+        assert_eq!(pattern.apply(&[1, 2]), "|1|b|");
+        assert_eq!(
+            "{|}1".parse::<NumberingPattern>(),
+            Err(NumberingPatternError::new(0..3, "`{|}` must follow a counting symbol"))
+        );
+    }
+
+    #[test]
+    fn test_numbering_overflow() {
+        let pattern: NumberingPattern = "1.a".parse().unwrap();
+        let with = |overflow| NumberingPattern { overflow, ..pattern.clone() };
+        let numbers = [1, 2, 3, 4];
+        assert_eq!(pattern.apply(&numbers), "1.b.c.d");
+        assert_eq!(with(NumberingOverflow::Truncate).apply(&numbers), "1.b");
+        assert_eq!(pattern.check(4), Ok(()));
+        assert_eq!(with(NumberingOverflow::Error).check(2), Ok(()));
+        assert_eq!(
+            with(NumberingOverflow::Error).check(4),
+            Err("too many numbers for pattern \"1.a\" (given: 4, counting symbols: 2)"
+                .into())
+        );
+    }
+
+    #[test]
+    fn test_numbering_underflow() {
+        let pattern: NumberingPattern = "1.1.1".parse().unwrap();
+        let with = |underflow| NumberingPattern { underflow, ..pattern.clone() };
+        assert_eq!(pattern.apply(&[2]), "2");
+        assert_eq!(
+            with(NumberingUnderflow::Placeholder("–".into())).apply(&[2]),
+            "2.–.–"
+        );
+        assert_eq!(with(NumberingUnderflow::Error).check(3), Ok(()));
+        assert_eq!(
+            with(NumberingUnderflow::Error).check(1),
+            Err("too few numbers for pattern \"1.1.1\" (given: 1, counting symbols: 3)"
+                .into())
+        );
+    }
+
+    #[test]
+    fn test_numbering_range() {
+        assert_eq!(collapsed("117", "119"), "19");
+        assert_eq!(collapsed("321", "328"), "28");
+        assert_eq!(collapsed("1087", "1089"), "89");
+        assert_eq!(collapsed("12", "12"), "12");
+        assert_eq!(collapsed("7", "9"), "9");
+        assert_eq!(collapsed("98", "102"), "102");
+        assert_eq!(collapsed("\u{2212}12", "\u{2212}15"), "\u{2212}15");
+
+        let pattern: NumberingPattern = "1.1:{|}a".parse().unwrap();
+        assert_eq!(pattern.apply_range_localized(&[1, 2], 4, "–", false, None), "1.2–4:");
+        assert_eq!(
+            pattern.apply_range_localized(&[1, 2, 3], 4, "–", false, None),
+            "1.2:c–d"
+        );
+        assert_eq!(pattern.apply_range_localized(&[1, 2], 2, "–", false, None), "1.2:");
+    }
+
+    #[test]
+    fn test_numbering_kind_parse() {
+        let kinds = [
+            (NumberingKind::Arabic, 0),
+            (NumberingKind::DevanagariNumber, 0),
+            (NumberingKind::SuperscriptNumber, 0),
+            (NumberingKind::AdlamNumber, 0),
+            (NumberingKind::LowerRoman, 0),
+            (NumberingKind::UpperRoman, 0),
+            (NumberingKind::LowerLatin, 1),
+            (NumberingKind::UpperLatin, 1),
+            (NumberingKind::LowerGreek, 0),
+            (NumberingKind::UpperGreek, 0),
+        ];
+        for (kind, start) in kinds {
+            for n in start..4000 {
+                assert_eq!(kind.parse(&kind.apply(n as i64)), Some(n), "{kind:?}");
+            }
+        }
+        for n in (1..7000).chain([10000, 15000, 15999]) {
+            let text = NumberingKind::Hebrew.apply(n as i64);
+            assert_eq!(NumberingKind::Hebrew.parse(&text), Some(n), "{text}");
+        }
+
+        assert_eq!(NumberingKind::UpperRoman.parse("IIII"), None);
+        assert_eq!(NumberingKind::UpperRoman.parse("iv"), None);
+        assert_eq!(NumberingKind::LowerGreek.parse("αμβʹ"), None);
+        assert_eq!(NumberingKind::Hebrew.parse("א׳"), Some(1));
+        assert_eq!(NumberingKind::Hebrew.parse("א׳ אלף"), Some(1000));
+        assert_eq!(NumberingKind::Hebrew.parse("טו׳ אלפים"), Some(15000));
+        assert_eq!(NumberingKind::Hebrew.parse("טו׳"), None);
+        assert_eq!(NumberingKind::Arabic.parse("007"), None);
+        assert_eq!(NumberingKind::Arabic.parse(""), None);
+        assert_eq!(NumberingKind::Symbol.parse("*"), None);
+    }
+
+    #[test]
+    fn test_numbering_pattern_parse() {
+        let parse = |pattern: &str, text| {
+            pattern.parse::<NumberingPattern>().unwrap().parse(text)
+        };
+        assert_eq!(parse("A.1)", "B.3)"), Some(vec![2, 3]));
+        assert_eq!(parse("A.1)", "B)"), Some(vec![2]));
+        assert_eq!(parse("I.1", "IV.2"), Some(vec![4, 2]));
+        assert_eq!(parse("1.1", "1.2.3"), Some(vec![1, 2, 3]));
+        assert_eq!(parse("1", "12"), Some(vec![12]));
+        assert_eq!(parse("001", "007"), Some(vec![7]));
+        assert_eq!(parse("1:{|}a.", "1:b."), Some(vec![1, 2]));
+        assert_eq!(parse("1a", "12b"), Some(vec![12, 2]));
+        assert_eq!(parse("11", "123"), None);
+        assert_eq!(parse("1.1", "1.x"), None);
+        assert_eq!(parse("(1)", "1"), None);
+    }
+
+    #[test]
+    fn test_numbering_pattern_parse_long() {
+        let pattern: NumberingPattern = "1".parse().unwrap();
+        assert_eq!(pattern.parse(&format!("{}x", "1".repeat(200))), None);
+
+        let pattern: NumberingPattern = "1.1".parse().unwrap();
+        let numbers = vec![1; 34];
+        assert_eq!(pattern.parse(&pattern.apply(&[1; 34])), Some(numbers));
+        assert_eq!(pattern.parse(&pattern.apply(&[1; 35])), None);
+    }
+
+    #[test]
+    fn test_numbering_serde() {
+        let pattern: NumberingPattern = "(1.a)".parse().unwrap();
+        let json = serde_json::to_string(&pattern).unwrap();
+        assert_eq!(json, r#""(1.a)""#);
+        assert_eq!(serde_json::from_str::<NumberingPattern>(&json).unwrap(), pattern);
+
+        let trimmed = Numbering::Pattern(pattern.clone()).trimmed();
+        let json = serde_json::to_string(&trimmed).unwrap();
+        assert_eq!(json, r#"{"pattern":"(1.a)","trim":{"prefix":true,"suffix":true}}"#);
+        let Numbering::Pattern(parsed) = serde_json::from_str(&json).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(Numbering::Pattern(parsed.clone()), trimmed);
+        assert_eq!(parsed.apply(&[1, 2]), "1.b");
+
+        let pattern: NumberingPattern = "1:{|}a|{'i'}".parse().unwrap();
+        let json = serde_json::to_string(&pattern).unwrap();
+        assert_eq!(serde_json::from_str::<NumberingPattern>(&json).unwrap(), pattern);
+
+        assert!(serde_json::from_str::<NumberingPattern>(r#""-""#).is_err());
+        assert!(serde_json::from_str::<NumberingPattern>("1").is_err());
+
+        let alphabet = Numbering::Alphabet(NumberingAlphabet {
+            digits: ["x".into(), "y".into()].into_iter().collect(),
+            zero: None,
+        });
+        let json = serde_json::to_value(&alphabet).unwrap();
+        assert_eq!(json["type"], "alphabet");
+        assert_eq!(json["repr"], alphabet.clone().into_value().repr().as_str());
+    }
+
+    #[test]
+    fn test_numbering_pattern_errors() {
+        let error = |pattern: &str| pattern.parse::<NumberingPattern>().unwrap_err();
+        assert_eq!(
+            error(""),
+            NumberingPatternError::new(0..0, "numbering pattern must not be empty")
+        );
+        assert_eq!(
+            error("(())"),
+            NumberingPatternError::new(0..4, "no counting symbol found")
+        );
+        assert_eq!(
+            error("1.{foo-bar}"),
+            NumberingPatternError::new(2..11, "unknown counting symbol {foo-bar}")
+        );
+        assert_eq!(
+            error("({|}1)"),
+            NumberingPatternError::new(1..4, "`{|}` must follow a counting symbol")
+        );
+    }
+
+    #[test]
+    fn test_numbering_pattern_value_roundtrip() {
+        let corpus = [
+            "1",
+            "1.a)",
+            "(I)",
+            "§ {First}.{1st}",
+            "{kanji}.",
+            "{1}",
+            "{ab c}1",
+            "Figure 001",
+            "01.1",
+            "{'0'}1",
+            "{'00'}01",
+            "{'A '}1.",
+            "{'Chapter '}1",
+            "{'x'}1",
+            r"1\\",
+            r"\1",
+            "1:{|}a.",
+            "1|.",
+            "1|a",
+            "(I){|} – 1",
+            "\u{0F21}",
+            "(₁.₁)",
+        ];
+        for source in corpus {
+            let pattern: NumberingPattern = source.parse().unwrap();
+            let value = pattern.clone().into_value();
+            assert_eq!(value, Value::Str(source.into()));
+            assert_eq!(value.repr(), EcoString::from(source).repr());
+            let parsed = value.cast::<NumberingPattern>().unwrap();
+            assert_eq!(parsed, pattern, "{source}");
+            assert_eq!(parsed.apply(&[1, 2, 3]), pattern.apply(&[1, 2, 3]));
+        }
+
+        let pattern = NumberingPattern {
+            overflow: NumberingOverflow::Truncate,
+            max_depth: NonZeroUsize::new(2),
+            .."(1.a)".parse().unwrap()
+        };
+        let value = pattern.clone().into_value();
+        assert_eq!(
+            value.repr(),
+            r#"numbering.pattern("(1.a)", overflow: "truncate", max-depth: 2)"#
+        );
+        assert_eq!(value.cast::<NumberingPattern>().unwrap(), pattern);
+    }
+
+    #[test]
+    fn test_numbering_pattern_multi_byte() {
+        let pattern: NumberingPattern = "§ \u{1E951}–\u{1E951}»".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [
+                (EcoString::from("§ "), NumberingKind::AdlamNumber),
+                (EcoString::from("–"), NumberingKind::AdlamNumber),
+            ]
+        );
+        assert_eq!(pattern.suffix, "»");
+
+        let pattern: NumberingPattern = "Nr. \u{104A1}+\u{104A1})".parse().unwrap();
+        assert_eq!(
+            pattern.pieces.as_slice(),
+            [
+                (EcoString::from("Nr. "), NumberingKind::OsmanyaNumber),
+                (EcoString::from("+"), NumberingKind::OsmanyaNumber),
+            ]
+        );
+        assert_eq!(pattern.suffix, ")");
+        assert_eq!(pattern.apply(&[10, 2]), "Nr. 𐒡𐒠+𐒢)");
+    }
+}
@@ -557,8 +557,9 @@ impl OutlineEntry {
         let Some(numbering) = outlinable.numbering() else { return Ok(None) };
         let loc = self.element_location().at(span)?;
         let styles = context.styles().at(span)?;
-        let numbers =
-            outlinable.counter().display_at_loc(engine, loc, styles, numbering)?;
+        let numbers = outlinable
+            .counter()
+            .display_at_loc(engine, loc, styles, numbering, span)?;
         Ok(Some(outlinable.prefix(numbers)))
     }
 
@@ -646,7 +647,8 @@ impl OutlineEntry {
             .page_numbering(loc)
             .cloned()
             .unwrap_or_else(|| NumberingPattern::from_str("1").unwrap().into());
-        Counter::new(CounterKey::Page).display_at_loc(engine, loc, styles, &numbering)
+        Counter::new(CounterKey::Page)
+            .display_at_loc(engine, loc, styles, &numbering, span)
     }
 }
 
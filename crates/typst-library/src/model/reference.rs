@@ -296,7 +296,13 @@ fn show_reference(
     elem: Content,
 ) -> SourceResult<Content> {
     let loc = elem.location().unwrap();
-    let numbers = counter.display_at_loc(engine, loc, styles, &numbering.trimmed())?;
+    let numbers = counter.display_at_loc(
+        engine,
+        loc,
+        styles,
+        &numbering.trimmed(),
+        reference.span(),
+    )?;
 
     let supplement = match reference.supplement(styles).as_ref() {
         Smart::Auto => supplement,
@@ -273,6 +273,7 @@ fn create(func: &Func, item: &syn::ItemFn) -> TokenStream {
     quote! {
         #[doc = #docs]
         #[allow(dead_code)]
+        #[allow(clippy::too_many_arguments)]
         #[allow(rustdoc::broken_intra_doc_links)]
         #item
 
@@ -1,12 +1,16 @@
 use std::num::NonZeroUsize;
 
 use krilla::page::{NumberingStyle, PageLabel};
-use typst_library::model::Numbering;
+use typst_library::model::{Numbering, NumberingLocale};
 
 pub(crate) trait PageLabelExt {
     /// Create a new `PageLabel` from a `Numbering` applied to a page
-    /// number.
-    fn generate(numbering: &Numbering, number: u64) -> Option<PageLabel>;
+    /// number in the page's locale.
+    fn generate(
+        numbering: &Numbering,
+        number: i64,
+        locale: NumberingLocale,
+    ) -> Option<PageLabel>;
 
     /// Creates an arabic page label with the specified page number.
     /// For example, this will display page label `11` when given the page
@@ -15,7 +19,11 @@ pub(crate) trait PageLabelExt {
 }
 
 impl PageLabelExt for PageLabel {
-    fn generate(numbering: &Numbering, number: u64) -> Option<PageLabel> {
+    fn generate(
+        numbering: &Numbering,
+        number: i64,
+        locale: NumberingLocale,
+    ) -> Option<PageLabel> {
         {
             let Numbering::Pattern(pat) = numbering else {
                 return None;
@@ -23,9 +31,10 @@ impl PageLabelExt for PageLabel {
 
             let (prefix, kind) = pat.pieces.first()?;
 
-            // If there is a suffix, we cannot use the common style optimisation,
-            // since PDF does not provide a suffix field.
-            let style = if pat.suffix.is_empty() {
+            // If there is a suffix, the number is not positive, or the numbers
+            // are not plain, like padded ones, we cannot use the common style
+            // optimisation, since PDF does not provide fields for them.
+            let style = if pat.suffix.is_empty() && number > 0 && pat.is_plain() {
                 use krilla::page::NumberingStyle as Style;
                 use typst_library::model::NumberingKind as Kind;
                 match kind {
@@ -44,7 +53,7 @@ impl PageLabelExt for PageLabel {
             // spec, we use the given prefix and an offset. Otherwise, everything
             // goes into prefix.
             let prefix = if style.is_none() {
-                Some(pat.apply(&[number]))
+                Some(pat.apply_localized(&[number], Some(locale)))
             } else {
                 (!prefix.is_empty()).then(|| prefix.clone())
             };
@@ -62,3 +71,43 @@ impl PageLabelExt for PageLabel {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::text::Lang;
+
+    use super::*;
+
+    #[test]
+    fn test_page_label_generate() {
+        let generate = |pattern: &str, number| {
+            let numbering = Numbering::Pattern(pattern.parse().unwrap());
+            let locale = NumberingLocale { lang: Lang::ENGLISH, region: None };
+            PageLabel::generate(&numbering, number, locale).unwrap()
+        };
+
+        let arabic = |prefix: Option<&str>, number| {
+            PageLabel::new(
+                Some(NumberingStyle::Arabic),
+                prefix.map(Into::into),
+                NonZeroUsize::new(number),
+            )
+        };
+        assert_eq!(generate("1", 7), arabic(None, 7));
+        assert_eq!(generate("p. 1", 7), arabic(Some("p. "), 7));
+
+        // Padded numbers can't be written with a style.
+        assert_eq!(generate("001", 7), PageLabel::new(None, Some("007".into()), None));
+        assert_eq!(
+            generate("p. 01", 7),
+            PageLabel::new(None, Some("p. 07".into()), None)
+        );
+
+        // Neither can numbers below one.
+        assert_eq!(generate("1", 0), PageLabel::new(None, Some("0".into()), None));
+        assert_eq!(
+            generate("1", -2),
+            PageLabel::new(None, Some("\u{2212}2".into()), None)
+        );
+    }
+}
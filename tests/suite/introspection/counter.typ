@@ -174,3 +174,14 @@ B
 #context test(c.get(), (100000000002,))
 #c.update(n => n + 2)
 #context test(c.get(), (100000000004,))
+
+--- counter-negative ---
+// Counters can go below zero.
+#let c = counter("c")
+#c.update(n => n - 2)
+#context test(c.get(), (-2,))
+#context test(c.display(), "\u{2212}2")
+#c.step()
+#context test(c.get(), (-1,))
+#c.update((1, -3))
+#context test(c.display("1.a"), "1.\u{2212}c")
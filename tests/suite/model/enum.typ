@@ -127,11 +127,11 @@ a + 0.
 + F
 
 --- enum-numbering-pattern-empty ---
-// Error: 22-24 invalid numbering pattern
+// Error: 22-24 numbering pattern must not be empty
 #set enum(numbering: "")
 
 --- enum-numbering-pattern-invalid ---
-// Error: 22-28 invalid numbering pattern
+// Error: 22-28 no counting symbol found
 #set enum(numbering: "(())")
 
 --- enum-numbering-huge ---
@@ -32,24 +32,24 @@
   5683, "͵εχπγ",
   9184, "͵θρπδ",
   9999, "͵θϡϙθ",
-  20000, "αΜβʹ",
-  20001, "αΜβʹ, αʹ",
-  97554, "αΜθʹ, ͵ζφνδ",
-  99999, "αΜθʹ, ͵θϡϙθ",
-  1000000, "αΜρʹ",
-  1000001, "αΜρʹ, αʹ",
-  1999999, "αΜρϙθʹ, ͵θϡϙθ",
-  2345678, "αΜσλδʹ, ͵εχοη",
-  9999999, "αΜϡϙθʹ, ͵θϡϙθ",
-  10000000, "αΜ͵α",
-  90000001, "αΜ͵θ, αʹ",
-  100000000, "βΜαʹ",
-  1000000000, "βΜιʹ",
-  2000000000, "βΜκʹ",
-  2000000001, "βΜκʹ, αʹ",
-  2000010001, "βΜκʹ, αΜαʹ, αʹ",
-  2056839184, "βΜκʹ, αΜ͵εχπγ, ͵θρπδ",
-  12312398676, "βΜρκγʹ, αΜ͵ασλθ, ͵ηχοϛ",
+  20000, "αμβʹ",
+  20001, "αμβʹ, αʹ",
+  97554, "αμθʹ, ͵ζφνδ",
+  99999, "αμθʹ, ͵θϡϙθ",
+  1000000, "αμρʹ",
+  1000001, "αμρʹ, αʹ",
+  1999999, "αμρϙθʹ, ͵θϡϙθ",
+  2345678, "αμσλδʹ, ͵εχοη",
+  9999999, "αμϡϙθʹ, ͵θϡϙθ",
+  10000000, "αμ͵α",
+  90000001, "αμ͵θ, αʹ",
+  100000000, "βμαʹ",
+  1000000000, "βμιʹ",
+  2000000000, "βμκʹ",
+  2000000001, "βμκʹ, αʹ",
+  2000010001, "βμκʹ, αμαʹ, αʹ",
+  2056839184, "βμκʹ, αμ͵εχπγ, ͵θρπδ",
+  12312398676, "βμρκγʹ, αμ͵ασλθ, ͵ηχοϛ",
 )
 #t(
   pat: sym.Alpha,
@@ -63,10 +63,13 @@
 
 // Hebrew.
 #t(pat: "א", step: 2, 9, "ט׳", "י״א", "י״ג")
+#t(pat: "א", 1000, "א׳ אלף", 5784, "ה׳תשפ״ד", 6000, "ו׳ אלפים", 5001, "ה׳א׳")
+#t(pat: "א", 1015, "א׳ט״ו", 1116, "א׳קט״ז", 15999, "טו׳תתקצ״ט")
 
 // Chinese.
 #t(pat: "一", step: 2, 9, "九", "十一", "十三", "十五", "十七", "十九")
 #t(pat: "壹", step: 2, 9, "玖", "拾壹", "拾叁", "拾伍", "拾柒", "拾玖")
+#t(pat: "第一章", 1, "第一章", "第二章", 12, "第十二章")
 
 // Japanese.
 #t(pat: "イ", "-", "イ", "ロ", "ハ", 47, "ス", "イイ", "イロ", "イハ", 2256, "スス", "イイイ")
@@ -105,6 +108,674 @@
 #t(pat: "⓵", 1, "⓵")
 #t(pat: "⓵", 10, "⓾")
 
+// Armenian.
+#t(pat: "ա", "-", "ա", "բ", "գ", 10, "ժ", "ժա", "ժբ", 40, "խ", 100, "ճ")
+#t(pat: "ա", 2024, "սիդ")
+#t(pat: "ա", 9999, "քջղթ", "10000")
+#test(numbering("Մաս ա.", 2), "Մաս բ.")
+#t(pat: "Ա", "-", "Ա", "Բ", 12, "ԺԲ", 40, "Խ", 100, "Ճ", 9999, "ՔՋՂԹ")
+#test(numbering("ՄԱՍ Ա.", 2), "ՄԱՍ Բ.")
+
+// Georgian.
+#t(pat: "ა", "-", "ა", "ბ", "გ", 9, "თ", "ი", "ია", 100, "რ", 2024, "ცკდ")
+#t(pat: "ა", 19999, "ჵჰშჟთ", "20000")
+#test(numbering("თავი ა.", 2), "თავი ბ.")
+
+// Ethiopic.
+#t(pat: "፩", "-", "፩", "፪", "፫", 10, "፲", "፲፩", "፲፪", 99, "፺፱", "፻", "፻፩")
+#t(pat: "፩", 1234, "፲፪፻፴፬", 10000, "፼", "፼፩", 1000000, "፻፼")
+#t(pat: "፩", 123456789, "፼፳፫፻፵፭፼፷፯፻፹፱")
+
+// Thai.
+#t(pat: "\u{0E51}", "๐", "๑", "๒", "๓", 9, "๙", "๑๐", 105, "๑๐๕")
+#test(numbering("\u{0E51}.\u{0E51}", 1, 2), "๑.๒")
+
+// Thai letters.
+#t(pat: "ก", "-", "ก", "ข", "ค", "ฆ", 42, "ฮ", "กก", "กข")
+#test(numbering("ก)", 3), "ค)")
+#test(numbering("ภาคผนวก ก", 2), "ภาคผนวก ข")
+
+// Lao.
+#t(pat: "\u{0ED1}", "໐", "໑", "໒", "໓", 10, "໑໐", 2024, "໒໐໒໔")
+
+// Khmer.
+#t(pat: "\u{17E1}", "០", "១", "២", "៣", 10, "១០", 105, "១០៥")
+
+// Myanmar.
+#t(pat: "\u{1041}", "၀", "၁", "၂", "၃", 10, "၁၀", 2024, "၂၀၂၄")
+
+// Tibetan.
+#t(pat: "\u{0F21}", "༠", "༡", "༢", "༣", 10, "༡༠", 108, "༡༠༨")
+
+// Tamil.
+#t(pat: "\u{0BE7}", "௦", "௧", "௨", "௩", 10, "௧௦", 1000, "௧௦௦௦")
+
+// Traditional Tamil.
+#t(pat: "௰", "-", "௧", "௨", 10, "௰", "௰௧", 20, "௨௰", 100, "௱", "௱௧")
+#t(pat: "௰", 1000, "௲", 2024, "௨௲௨௰௪", 9999, "௯௲௯௱௯௰௯", "௰௲")
+#t(pat: "௰", 12345, "௰௨௲௩௱௪௰௫", 1000000, "௲௲")
+
+// Telugu.
+#t(pat: "\u{0C67}", "౦", "౧", "౨", "౩", 10, "౧౦", 123, "౧౨౩")
+
+// Kannada.
+#t(pat: "\u{0CE7}", "೦", "೧", "೨", "೩", 10, "೧೦", 123, "೧೨೩")
+#test(numbering("\u{0CE7}.\u{0CE7})", 1, 2, 3), "೧.೨.೩)")
+
+// Malayalam.
+#t(pat: "\u{0D67}", "൦", "൧", "൨", "൩", 10, "൧൦", 123, "൧൨൩")
+
+// Gujarati.
+#t(pat: "\u{0AE7}", "૦", "૧", "૨", "૩", 10, "૧૦", 123, "૧૨૩")
+
+// Gurmukhi.
+#t(pat: "\u{0A67}", "੦", "੧", "੨", "੩", 10, "੧੦", 123, "੧੨੩")
+
+// Odia.
+#t(pat: "\u{0B67}", "୦", "୧", "୨", "୩", 10, "୧୦", 123, "୧୨୩")
+
+// Sinhala lith.
+#t(pat: "\u{0DE7}", "෦", "෧", "෨", "෩", 10, "෧෦", 105, "෧෦෫")
+
+// Mongolian.
+#t(pat: "\u{1811}", "᠐", "᠑", "᠒", "᠓", 10, "᠑᠐", 2024, "᠒᠐᠒᠔")
+
+// N'Ko.
+#t(pat: "\u{07C1}", "߀", "߁", "߂", "߃", 10, "߁߀", 2024, "߂߀߂߄")
+#test(numbering("\u{07C1}", 12).codepoints(), ("\u{07C1}", "\u{07C2}"))
+#test(numbering("\u{0661}", 12).codepoints(), ("\u{0661}", "\u{0662}"))
+
+// Adlam.
+#t(pat: "\u{1E951}", "𞥐", "𞥑", "𞥒", "𞥓", 10, "𞥑𞥐", 2024, "𞥒𞥐𞥒𞥔")
+#test(numbering("§ \u{1E951}–\u{1E951}»", 1, 2), "§ 𞥑–𞥒»")
+
+// Cyrillic.
+#t(pat: "а", "-", "а", "б", "в", "г", "д", "е", "ж", "з", "и", "к")
+#t(pat: "а", 28, "я", "аа", "аб")
+#test(numbering("Глава а.", 2), "Глава б.")
+#test(numbering("Глава 1.", 2), "Глава 2.")
+#t(pat: "А", "-", "А", "Б", "В", 9, "И", "К", 28, "Я", "АА", "АБ")
+#test(numbering("ГЛАВА А.", 2), "ГЛАВА Б.")
+
+// Cyrillic and Latin letters are distinct.
+#test(numbering("\u{0410}", 2), "Б")
+#test(numbering("\u{0041}", 2), "B")
+
+// Arabic abjad.
+#t(pat: "أ", "-", "أ", "ب", "ج", "د", 28, "غ", "أأ", "أب")
+#test(numbering("أ.", 2), "ب.")
+#test(numbering("(أ)", 3).codepoints(), ("(", "ج", ")"))
+#test(numbering("مسألة أ", 2), "مسألة ب")
+
+// Arabic hijāʾī.
+#t(pat: "ا", "-", "ا", "ب", "ت", "ث", 28, "ي", "اا", "اب")
+#test(numbering("الفصل ا", 2), "الفصل ب")
+
+// Persian.
+#t(pat: "آ", "-", "الف", "ب", "پ", "ت", 32, "ی", "الف\u{200C}الف", "الف\u{200C}ب")
+#test(numbering("پیوست آ", 1), "پیوست الف")
+#test(numbering("آزمون آ", 2), "آزمون ب")
+
+// Japanese daiji.
+#t(pat: "壱", "〇", "壱", "弐", "参", "四", 10, "壱拾", "壱拾壱", 20, "弐拾", "弐拾壱")
+#t(pat: "壱", 100, "壱百", 1000, "壱千", 2024, "弐千弐拾四", 10000, "壱萬")
+
+// Circled katakana.
+#t(pat: "㋐", "-", "㋐", "㋑", "㋒", 47, "㋾", "㋐㋐", "㋐㋑")
+
+// Circled ideographs.
+#t(pat: "㊀", "-", "㊀", "㊁", "㊂", 10, "㊉", "㊀㊀", "㊀㊁")
+
+// Parenthesized numbers.
+#t(pat: "⑴", "-", "⑴", "⑵", "⑶", 20, "⒇", "(21)", "(22)", 100, "(100)")
+
+// Parenthesized Latin letters.
+#t(pat: "⒜", "-", "⒜", "⒝", "⒞", 26, "⒵", "(aa)", "(ab)", 52, "(az)", "(ba)")
+
+// Parenthesized ideographs.
+#t(pat: "㈠", "-", "㈠", "㈡", "㈢", 10, "㈩", "（十一）", "（十二）")
+#test(numbering("㈠①", 2, 3), "㈡③")
+
+// Fullwidth numbers.
+#t(pat: "１", "０", "１", "２", "３", 10, "１０", 2024, "２０２４")
+
+// Superscript numbers.
+#t(pat: "¹", "⁰", "¹", "²", "³", "⁴", 10, "¹⁰", "¹¹", "¹²", 2024, "²⁰²⁴")
+
+// Subscript numbers.
+#t(pat: "₁", "₀", "₁", "₂", "₃", 10, "₁₀", 2024, "₂₀₂₄")
+
+// Negative circled numbers.
+#t(pat: "❶", "-", "❶", "❷", "❸", 10, "❿", "⓫", "⓬", 20, "⓴", "❶❶")
+
+// Sans-serif circled numbers.
+#t(pat: "➀", "-", "➀", "➁", "➂", 10, "➉", "➀➀")
+#t(pat: "➊", "-", "➊", "➋", "➌", 10, "➓", "➊➊")
+
+// Numbers with a full stop.
+#t(pat: "⒈", "-", "⒈", "⒉", "⒊", 20, "⒛", "21.", "22.")
+#test(numbering("⒈.", 2), "⒉.")
+
+// Tally marks.
+#t(pat: "\u{1D377}", "-", "𝍷", "𝍷𝍷", "𝍷𝍷𝍷", "𝍷𝍷𝍷𝍷", "𝍸", "𝍸𝍷", "𝍸𝍷𝍷", 12, "𝍸𝍸𝍷𝍷")
+#t(pat: "\u{1D377}", 100, "𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸", "𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍸𝍷")
+#t(pat: "\u{1D377}", 105, "𝍸×21", "𝍸×21𝍷", 1000000000, "𝍸×200000000")
+
+// Counting rods.
+#t(pat: "\u{1D360}", "〇", "𝍠", "𝍡", "𝍢", 6, "𝍥", 10, "𝍩〇", "𝍩𝍠", 60, "𝍮〇")
+#t(pat: "\u{1D360}", 606, "𝍥〇𝍥", 2024, "𝍪〇𝍪𝍣")
+
+// Suzhou.
+#t(pat: "〡", "〇", "〡", "〢", "〣", "〤", 9, "〩", "〡〇", "〡一", "〡二", "〡三", "〡〤")
+#t(pat: "〡", 22, "〢二", 105, "〡〇〥", 111, "〡一〡", 2024, "〢〇〢〤")
+
+// Maya.
+#t(pat: "\u{1D2E1}", "𝋠", "𝋡", "𝋢", "𝋣", 19, "𝋳", "𝋡𝋠", "𝋡𝋡", 400, "𝋡𝋠𝋠", 2024, "𝋥𝋡𝋤")
+
+// Kaktovik.
+#t(pat: "\u{1D2C1}", "𝋀", "𝋁", "𝋂", "𝋃", 19, "𝋓", "𝋁𝋀", "𝋁𝋁", 400, "𝋁𝋀𝋀", 2023, "𝋅𝋁𝋃")
+
+// Roman numerals using the dedicated Unicode codepoints.
+#t(pat: "Ⅰ", "N", "Ⅰ", "Ⅱ", "Ⅲ", "Ⅳ", 12, "Ⅻ", "ⅩⅢ", "ⅩⅣ", 49, "ⅩⅬⅨ", 1984, "ⅯⅭⅯⅬⅩⅩⅩⅣ")
+#t(pat: "ⅰ", "n", "ⅰ", "ⅱ", "ⅲ", "ⅳ", 12, "ⅻ", "ⅹⅲ", "ⅹⅳ", 49, "ⅹⅼⅸ", 1984, "ⅿⅽⅿⅼⅹⅹⅹⅳ")
+
+// Clock faces.
+#t(pat: "🕐", "-", "🕐", "🕑", "🕒", 12, "🕛", "🕐🕐", 156, "🕛🕛")
+#t(pat: "(🕐) ", 1, "(🕐) ", "(🕑) ", 13, "(🕐🕐) ")
+
+// Die faces.
+#t(pat: "⚀", "-", "⚀", "⚁", "⚂", "⚃", "⚄", "⚅", "⚅⚀", "⚅⚁", 12, "⚅⚅", "⚅⚅⚀")
+
+// Braille.
+#t(pat: "⠼", "⠼⠚", "⠼⠁", "⠼⠃", "⠼⠉", 9, "⠼⠊", "⠼⠁⠚", 105, "⠼⠁⠚⠑")
+
+// Bopomofo.
+#t(pat: "ㄅ", "-", "ㄅ", "ㄆ", "ㄇ", "ㄈ", 37, "ㄩ", "ㄅㄅ", "ㄅㄆ")
+
+// Devanagari Consonants.
+#t(pat: "\u{0915}", 1, "क")
+#t(pat: "\u{0915}", 33, "ह")
+#t(pat: "\u{0915}", 34, "कक", "कख")
+#t(pat: "(\u{0915})", 2, "(ख)", "(ग)")
+#test(numbering("क्रम \u{0915}", 2), "क्रम ख")
+
+// Balinese.
+#t(pat: "\u{1B51}", "᭐", "᭑", "᭒", "᭓", 10, "᭑᭐", 2024, "᭒᭐᭒᭔")
+
+// Javanese.
+#t(pat: "\u{A9D1}", "꧐", "꧑", "꧒", "꧓", 10, "꧑꧐", 2024, "꧒꧐꧒꧔")
+
+// Cham.
+#t(pat: "\u{AA51}", "꩐", "꩑", "꩒", "꩓", 10, "꩑꩐", 2024, "꩒꩐꩒꩔")
+#test(numbering("1 / \u{AA51}", 3, 3), "3 / ꩓")
+
+// Vai.
+#t(pat: "\u{A621}", "꘠", "꘡", "꘢", "꘣", 10, "꘡꘠", 100, "꘡꘠꘠")
+
+// Osmanya.
+#t(pat: "\u{104A1}", "𐒠", "𐒡", "𐒢", "𐒣", 10, "𐒡𐒠", 2024, "𐒢𐒠𐒢𐒤")
+#test(numbering("No. \u{104A1}.", 12), "No. 𐒡𐒢.")
+
+// Tifinagh.
+#t(pat: "ⴰ", "-", "ⴰ", "ⴱ", "ⴳ", "ⴳⵯ", "ⴷ", 33, "ⵥ", "ⴰⴰ", "ⴰⴱ")
+#test(numbering("ⴰⴷⵍⵙ ⴰ", 2), "ⴰⴷⵍⵙ ⴱ")
+
+// Syriac.
+#t(pat: "ܐ", "-", "ܐ", "ܒ", "ܓ", 10, "ܝ", "ܝܐ", 15, "ܝܗ", 444, "ܬܡܕ", 900, "ܬܬܩ")
+#t(pat: "ܐ", 1000, "ܐ\u{0748}", 2024, "ܒ\u{0748}ܟܕ", 9999, "ܛ\u{0748}ܬܬܩܨܛ", 10000, "10000")
+#test(numbering("(ܐ)", 11), "(ܝܐ)")
+#test(numbering("ܐ.ܐ", 1, 12), "ܐ.ܝܒ")
+#test(numbering("ܦܪܩܐ ܐ", 2), "ܦܪܩܐ ܒ")
+
+// Coptic epact.
+#t(pat: "\u{102E1}", "-", "𐋡", "𐋢", "𐋣", 10, "𐋪", "𐋪𐋡", 105, "𐋳𐋥")
+#t(pat: "\u{102E1}", 999, "𐋻𐋲𐋩", "1000")
+#test(numbering("[\u{102E1}]", 20), "[𐋫]")
+
+// Glagolitic.
+#t(pat: "Ⰰ", "-", "Ⰰ", "Ⰱ", "Ⰲ", 10, "Ⰹ", "ⰉⰀ", 15, "ⰉⰄ", 20, "Ⰻ")
+#t(pat: "Ⰰ", 888, "ⰛⰑⰇ", 1000, "Ⱍ", 2024, "ⰞⰋⰃ", 2999, "ⰞⰜⰒⰈ", "3000")
+#test(numbering("ⰃⰎⰀⰂⰀ Ⰰ", 2), "ⰃⰎⰀⰂⰀ Ⰱ")
+
+// Aegean.
+#t(pat: "\u{10107}", "-", "𐄇", "𐄈", "𐄉", 10, "𐄐", "𐄐𐄇", 300, "𐄛")
+#t(pat: "\u{10107}", 2024, "𐄣𐄑𐄊", 99999, "𐄳𐄪𐄡𐄘𐄏", "100000")
+
+// Runic.
+#t(pat: "ᚠ", "-", "ᚠ", "ᚢ", "ᚦ", "ᚨ", 24, "ᛟ", "ᚠᚠ", "ᚠᚢ", 600, "ᛟᛟ")
+#test(numbering("ᚠᛖᚺᚢ ᚠ", 2), "ᚠᛖᚺᚢ ᚢ")
+
+--- numbering-named ---
+#test(numbering("{kanji}.", 12), "十二.")
+#test(numbering("{greek-letter})", 2), "β)")
+#test(numbering("{Greek-letter}", 25), "ΑΑ")
+#test(numbering("{hebrew-letter}", 23), "אא")
+#test(numbering("{1st}", 22), "22nd")
+#test(numbering("Ch. {One}", 23), "Ch. Twenty-Three")
+#test(numbering("{first}", 3), "third")
+#test(numbering("{First} Schedule", 3), "Third Schedule")
+#test(numbering("{第一}章", 12), "第十二章")
+#test(numbering("{1}.{kanji}", 2, 3), "{2}.三")
+#test(numbering("{words}", 21), "twenty-one")
+#set text(lang: "de")
+#context test(numbering("{words}", 21), "einundzwanzig")
+
+--- numbering-locale-counters ---
+#set text(lang: "de")
+#set heading(numbering: "{words}")
+#show heading: none
+= Einleitung
+#counter(figure.where(kind: image)).step()
+#context test(counter(heading).display(), "eins")
+#context test(counter(figure.where(kind: image)).display("{words}"), "eins")
+#context test(counter(page).display("{words}"), "eins")
+
+--- numbering-named-unknown ---
+// Error: 13-18 unknown counting symbol {foo}
+#numbering("{foo}", 1)
+
+--- numbering-named-unclosed ---
+#test(numbering("1.{xyz", 2), "2.{xyz")
+#test(numbering("{xy 1", 2), "{xy 2")
+
+--- numbering-pattern-empty ---
+// Error: 12-14 numbering pattern must not be empty
+#numbering("", 1)
+
+--- numbering-pattern-no-counting-symbol ---
+// Error: 13-17 no counting symbol found
+#numbering("(())", 1)
+
+--- numbering-pattern-error-escaped ---
+// Error: 12-26 unknown counting symbol {foo} (at byte 2)
+#numbering("1.\u{7B}foo}", 1)
+
+--- numbering-pattern-error-set-rule ---
+// Error: 25-34 unknown counting symbol {foo} (at byte 2)
+#set heading(numbering: "1.{foo}")
+
+--- numbering-escape ---
+#test(numbering("{'A'} 1.", 3), "A 3.")
+#test(numbering("1{'a'}1", 2, 3), "2a3")
+#test(numbering("1{'i'}", 4), "4i")
+#test(numbering("{'A'}A", 2), "AB")
+#test(numbering("A{'A'}", 2), "BA")
+#test(numbering("{'{words}'} 1", 2), "{words} 2")
+#test(numbering("{'Appendix'} A", 2), "Appendix B")
+#test(numbering("{'it''}}1", 2), "it'}2")
+#test(numbering("1.{'a", 2, 3), "2.{'c")
+
+--- numbering-backslash ---
+// Backslashes are just text.
+#test(numbering("1\\", 2), "2\\")
+#test(numbering("\\1", 2), "\\2")
+#test(numbering("Ch\\apter 1", 2, 3), "Ch\\bpter 3")
+
+--- numbering-padding ---
+#test(numbering("001", 7), "007")
+#test(numbering("001", 123), "123")
+#test(numbering("001", 1234), "1234")
+#test(numbering("01.1", 3, 4), "03.4")
+#test(numbering("1.01", 3, 4, 5), "3.04.05")
+#test(numbering("No. 001", 0), "No. 000")
+#test(numbering("\u{0660}\u{0661}", 5), "\u{0660}\u{0665}")
+#test(numbering("0a", 2), "0b")
+#test(numbering("{'0'}1", 2), "02")
+
+--- numbering-pattern-roundtrip ---
+#let roundtrip(pat) = {
+  set heading(numbering: pat)
+  context test(heading.numbering, pat)
+}
+
+#roundtrip("1.a)")
+#roundtrip("\u{0ED1}.")
+#roundtrip("\u{0F21}")
+#roundtrip("§ \u{0F21}.\u{0F21}")
+#roundtrip("\u{0A67}.")
+#roundtrip("₁")
+#roundtrip("(₁.₁)")
+#roundtrip("{kanji}.")
+#roundtrip("§ {First}.{1st}")
+#roundtrip("{'A '}1.")
+#roundtrip("{'Chapter '}1")
+#roundtrip("{'{words} '}1")
+#roundtrip("{'it''}}1")
+#roundtrip("1\\")
+#roundtrip("\\1")
+#roundtrip("No. 001")
+#roundtrip("01.1")
+#roundtrip("{'0'}1")
+#roundtrip("{'00'}01")
+#roundtrip("1:{|}a.")
+#roundtrip("(I){|} – 1")
+#roundtrip("1|a")
+#roundtrip("1|.")
+#roundtrip("{'x'}1")
+#roundtrip(numbering.pattern("1.a", overflow: "truncate"))
+#roundtrip(numbering.pattern("(1.a)", trim: "prefix", zero: "o"))
+
+--- numbering-pattern-options ---
+#let pat = numbering.pattern("(1.a)", trim: true)
+#test(numbering(pat, 1, 2), "1.b")
+#test(numbering(pat.with(overflow: "truncate"), 1, 2, 3), "1.b")
+#test(numbering(pat, trim: false, 1, 2), "(1.b)")
+#test(numbering.pattern("1.a"), "1.a")
+#test(str(type(pat)), "numbering-pattern")
+#test(repr(pat), "numbering.pattern(\"(1.a)\", trim: true)")
+#test(numbering.pattern(pat, max-depth: 2).with(max-depth: none), pat)
+
+--- numbering-pattern-options-unknown ---
+// Error: 37-47 unexpected argument: foo
+#numbering.pattern("1", trim: true, foo: false)
+
+--- numbering-options-not-pattern ---
+// Error: 2-56 `trim` only applies if the numbering is a pattern
+#numbering((..nums) => nums.pos().len(), trim: true, 1)
+
+--- numbering-repetition-limit ---
+#test(numbering("*", 120), "‖" * 20)
+#test(numbering("*", 121), "*×21")
+#test(numbering("*", 9223372036854775807), "*×1537228672809129302")
+#test(numbering("⚀", 127), "⚅×21⚀")
+#test(numbering("\u{1D377}", 9223372036854775807), "𝍸×1844674407370955161𝍷𝍷")
+
+--- numbering-alphabet ---
+#let xyz = numbering.alphabet(("x", "y", "z"))
+#test(numbering(xyz, 1), "x")
+#test(numbering(xyz, 3), "z")
+#test(numbering(xyz, 4), "xx")
+#test(numbering(xyz, 5), "xy")
+#test(numbering(xyz, 0), "-")
+#test(numbering(xyz, 1, 2), "x.y")
+#test(numbering(xyz, -2), "\u{2212}y")
+#test(xyz, numbering.alphabet(("x", "y", "z")))
+#test(repr(xyz), "numbering.alphabet((\"x\", \"y\", \"z\"))")
+
+#let nato = numbering.alphabet(("Alfa", "Bravo"), zero: "Nil")
+#test(numbering(nato, 0), "Nil")
+#test(numbering(nato, 5), "AlfaBravoAlfa")
+#test(
+  repr(nato),
+  "numbering.alphabet((\"Alfa\", \"Bravo\"), zero: \"Nil\")",
+)
+
+--- numbering-alphabet-counter ---
+#let xyz = numbering.alphabet(("x", "y", "z"))
+#let c = counter("alphabet")
+#c.update((1, 4))
+#context test(c.display(xyz), "x.xx")
+
+--- numbering-alphabet-too-short ---
+// Error: 2-28 alphabet must have at least two digits
+#numbering.alphabet(("x",))
+
+--- numbering-symbols ---
+#let marks = numbering.symbols(("*", "†", "※"))
+#test(numbering(marks, 0), "-")
+#test(numbering(marks, 1), "*")
+#test(numbering(marks, 3), "※")
+#test(numbering(marks, 4), "**")
+#test(numbering(marks, 9), "※※※")
+#test(numbering(marks, 2, 4), "†.**")
+#test(repr(marks), "numbering.symbols((\"*\", \"†\", \"※\"))")
+
+#let stars = numbering.symbols(("*",))
+#test(numbering(stars, 1), "*")
+#test(numbering(stars, 3), "***")
+#test(numbering(stars, 21), "*×21")
+#test(repr(stars), "numbering.symbols((\"*\",))")
+
+#let pairs = numbering.symbols(("*", "**", "†", "††"))
+#test(numbering(pairs, 2), "**")
+#test(numbering(pairs, 4), "††")
+#test(numbering(pairs, 6), "****")
+#test(numbering(pairs, 8), "††††")
+
+--- numbering-symbols-empty ---
+// Error: 2-23 symbols must not be empty
+#numbering.symbols(())
+
+--- numbering-zero ---
+#test(numbering("a", 0), "-")
+#test(numbering("a", zero: "o", 0), "o")
+#test(numbering("(I)", zero: "N", 0), "(N)")
+#test(numbering("a.1", zero: "o", 0, 0), "o.o")
+#test(numbering("A.a.i", zero: "–", 2, 0, 3), "B.–.iii")
+#test(numbering("a", zero: "o", 1), "a")
+#test(numbering("001", zero: "none", 0), "none")
+#test(numbering("1", zero: "–", 0), "–")
+#test(numbering("1", 0), "0")
+
+--- numbering-roman-large ---
+#let o = "\u{0305}"
+#test(numbering("I", 3999), "MMMCMXCIX")
+#test(numbering("I", 4000), "I" + o + "V" + o)
+#test(numbering("I", 9000), "I" + o + "X" + o)
+#test(numbering("I", 1000000), "M" + o)
+#test(numbering("i", 2000001), "m" + o + "m" + o + "i")
+#test(numbering("I", 4000000000), "I" + o * 3 + "V" + o * 3)
+#test(numbering("I", roman: "plain", 3999), "MMMCMXCIX")
+#test(numbering("I", roman: "plain", 4000), "MMMM")
+#test(numbering("I", roman: "plain", 1000000), "M×1000")
+#test(numbering("i", roman: "plain", 2000001), "m×2000i")
+#test(numbering("I.i", roman: "plain", 5000, -4000), "MMMMM.\u{2212}mmmm")
+
+--- numbering-greek-keraia ---
+#test(numbering("α", 1), "αʹ")
+#test(numbering("α", keraia: false, 1), "α")
+#test(numbering("Α", 1), "Αʹ")
+#test(numbering("Α", keraia: false, 1), "Α")
+#test(numbering("α", 1000), "͵α")
+#test(numbering("α", keraia: false, 1000), "͵α")
+#test(numbering("Α", 1000), "͵Α")
+#test(numbering("Α", keraia: false, 1000), "͵Α")
+#test(numbering("α", 10001), "αμαʹ, αʹ")
+#test(numbering("α", keraia: false, 10001), "αμα α")
+#test(numbering("Α", 10001), "αΜΑʹ, Αʹ")
+#test(numbering("Α", keraia: false, 10001), "αΜΑ Α")
+#test(numbering("α", 123456789), "βμαʹ, αμ͵βτμε, ͵ϛψπθ")
+#test(numbering("α", keraia: false, 123456789), "βμα αμ͵βτμε ͵ϛψπθ")
+#test(numbering("Α", 123456789), "βΜΑʹ, αΜ͵ΒΤΜΕ, ͵ϚΨΠΘ")
+#test(numbering("Α", keraia: false, 123456789), "βΜΑ αΜ͵ΒΤΜΕ ͵ϚΨΠΘ")
+
+--- numbering-chinese-region ---
+#test(numbering("一", 10000), "一万")
+#test(numbering("壹", 2), "贰")
+#set text(lang: "zh", region: "tw")
+#context test(numbering("一", 10000), "一萬")
+#context test(numbering("壹", 2), "貳")
+#context test(numbering("第一章", 10000), "第一萬章")
+#set text(region: "cn")
+#context test(numbering("一", 10000), "一万")
+
+--- numbering-introspection ---
+#test(numbering.pieces("1.a)"), 2)
+#test(numbering.prefix("{'Chapter '}1.a", 0), "Chapter ")
+#test(numbering.prefix("{'Chapter '}1.a", 1), ".")
+#test(numbering.suffix("1.a)"), ")")
+#test(numbering.suffix("1"), "")
+#test(numbering.kind("1.a)", 0), "1")
+#test(numbering.kind("(I)", 0), "I")
+#test(numbering.kind("001", 0), "1")
+#test(numbering.kind("{kanji}", 0), "{kanji}")
+
+--- numbering-introspection-out-of-bounds ---
+// Error: 2-26 pattern index out of bounds (index: 2, len: 2)
+#numbering.kind("1.a", 2)
+
+--- numbering-at ---
+#test(numbering.at("A.1)", 0, 2), "B)")
+#test(numbering.at("A.1)", 1, 2), "2)")
+#test(numbering.at("A.1)", 3, 2), "2)")
+#test(numbering.at("(a.i)", 1, 4), "(iv)")
+#test(numbering.at("0001", 5, 7), "0007")
+
+--- numbering-trim ---
+#test(numbering("(1.a)", 1, 2), "(1.b)")
+#test(numbering("(1.a)", trim: true, 1, 2), "1.b")
+#test(numbering("(1.a)", trim: true, 1, 2, 3), "1.b.c")
+#test(numbering("Chapter 1.", trim: true, 3), "3")
+#test(numbering("§1.a)", trim: false, 1, 2), "§1.b)")
+#test(numbering("§1.a)", trim: "prefix", 1, 2), "1.b)")
+#test(numbering("§1.a)", trim: "suffix", 1, 2), "§1.b")
+#test(numbering("§1.a)", trim: "suffix", 1, 2, 3), "§1.b.c")
+
+--- numbering-piece-suffix ---
+#test(numbering("1:{|}a.", 1), "1:")
+#test(numbering("1:{|}a.", 1, 2), "1:b.")
+#test(numbering("1:{|}a.", 1, 2, 3), "1:b.c.")
+#test(numbering("1:{|}a.", trim: true, 1), "1")
+
+--- numbering-piece-suffix-compat ---
+// A `|` on its own is text, like before per-piece suffixes.
+#test(numbering("|1|", 2), "|2|")
+#test(numbering("1|", 2), "2|")
+#test(numbering("1|a", 1, 2), "1|b")
+#test(numbering("1|a", 1), "1")
+
+--- numbering-piece-suffix-leading ---
+// Error: 13-16 `{|}` must follow a counting symbol
+#numbering("{|}1", 1)
+
+--- numbering-overflow ---
+#test(numbering("1.a", 1, 2, 3, 4), "1.b.c.d")
+#test(numbering("1.a", overflow: "repeat", 1, 2, 3, 4), "1.b.c.d")
+#test(numbering("1.a", overflow: "truncate", 1, 2, 3, 4), "1.b")
+#test(numbering("(1.a)", overflow: "truncate", 1, 2, 3, 4), "(1.b)")
+#test(numbering("1.a", overflow: "error", 1, 2), "1.b")
+
+--- numbering-overflow-error ---
+// Error: 2-49 too many numbers for pattern "1.a" (given: 4, counting symbols: 2)
+#numbering("1.a", overflow: "error", 1, 2, 3, 4)
+
+--- numbering-overflow-error-heading ---
+#set heading(numbering: numbering.pattern("1", overflow: "error"))
+= First
+// Error: 1-7 too many numbers for pattern "1" (given: 2, counting symbols: 1)
+== Sub
+
+--- numbering-underflow ---
+#test(numbering("1.1.", 1), "1.")
+#test(numbering("1.1.1", 2), "2")
+#test(numbering("1.1.1", underflow: none, 2), "2")
+#test(numbering("1.1.1", underflow: "–", 2), "2.–.–")
+#test(numbering("(1.a)", underflow: "?", 2), "(2.?)")
+#test(numbering("1:{|}a.", underflow: "–", 2), "2:–.")
+#test(numbering("1.1.1", underflow: "error", 1, 2, 3), "1.2.3")
+
+--- numbering-underflow-error ---
+// Error: 2-43 too few numbers for pattern "1.1.1" (given: 1, counting symbols: 3)
+#numbering("1.1.1", underflow: "error", 1)
+
+--- numbering-underflow-error-heading ---
+#set heading(numbering: numbering.pattern("1.1", underflow: "error"))
+// Error: 1-8 too few numbers for pattern "1.1" (given: 1, counting symbols: 2)
+= First
+
+--- numbering-underflow-error-counter ---
+#let pat = numbering.pattern("1.1", underflow: "error")
+#counter("x").step()
+// Error: 10-35 too few numbers for pattern "1.1" (given: 1, counting symbols: 2)
+#context counter("x").display(pat)
+
+--- numbering-map ---
+#let odd = numbering.map("1", step: 2)
+#test(range(1, 5).map(n => numbering(odd, n)), ("1", "3", "5", "7"))
+#test(numbering(numbering.map("1.a", offset: 5), 1, 2), "5.f")
+#test(numbering(numbering.map("1", offset: 0), 1), "0")
+#test(numbering(numbering.map("1", offset: -1), 1), "\u{2212}1")
+#test(numbering(numbering.map((..n) => n.pos(), offset: 3, step: 3), 1, 2), (3, 6))
+#test(numbering(numbering.map(numbering.map("1", step: 2), offset: 10), 2), "21")
+#test(repr(numbering.map("1.", offset: 5)), "numbering.map(\"1.\", offset: 5)")
+#test(numbering.map("1"), numbering.map("1", offset: 1, step: 1))
+
+--- numbering-map-counter ---
+#set heading(numbering: numbering.map("1.", offset: 5))
+#context test(heading.numbering, numbering.map("1.", offset: 5))
+#counter(heading).update(1)
+#context test(counter(heading).display(), "5.")
+
+--- numbering-reversed ---
+#let countdown = numbering.reversed("1", 10)
+#test(numbering(countdown, 1), "10")
+#test(numbering(countdown, 2), "9")
+#test(numbering(countdown, 10), "1")
+#test(numbering(countdown, 11), "0")
+#test(numbering(countdown, 100), "0")
+#test(numbering(numbering.reversed("a.a", 3), 1, 2, 3), "c.b.a")
+#test(numbering(numbering.reversed("1", 9223372036854775807), 0), "9223372036854775807")
+#test(repr(countdown), "numbering.reversed(\"1\", 10)")
+
+--- numbering-reversed-counter ---
+#set heading(numbering: numbering.reversed("1.", 3))
+#context test(heading.numbering, numbering.reversed("1.", 3))
+#counter(heading).update(1)
+#context test(counter(heading).display(), "3.")
+
+--- numbering-range ---
+#test(numbering.range("1", 3, 3), "3")
+#test(numbering.range("1", 3, 4), "3–4")
+#test(numbering.range("1", 3, 5), "3–5")
+#test(numbering.range("i", 3, 5), "iii–v")
+#test(numbering.range("(I)", 3, 5, separator: " to "), "(III to V)")
+#test(numbering.range("1.1", (2, 3), 5), "2.3–5")
+#test(numbering.range("1.a)", (2, 3), 5), "2.c–e)")
+#test(numbering.range("1", 117, 119), "117–119")
+#test(numbering.range("1", 117, 119, collapse: true), "117–19")
+#test(numbering.range("1", 1087, 1089, collapse: true), "1087–89")
+#test(numbering.range("1", 13, 15, collapse: true), "13–15")
+#test(numbering.range("1", 498, 532, collapse: true), "498–532")
+#test(numbering.range("1", 98, 102, collapse: true), "98–102")
+#test(numbering.range("i", 3, 5, collapse: true), "iii–v")
+#test(numbering.range(numbering.symbols(("*", "†")), 1, 2), "*–†")
+
+--- numbering-range-empty ---
+// Error: 2-29 range start must not be empty
+#numbering.range("1", (), 2)
+
+--- numbering-max-depth ---
+#test(numbering("1.1", max-depth: 3, 1, 2), "1.2")
+#test(numbering("1.1", max-depth: 3, 1, 2, 3), "1.2.3")
+#test(numbering("1.1", max-depth: 3, 1, 2, 3, 4, 5, 6), "1.2.….6")
+#test(numbering("1.1", max-depth: 1, 1, 2, 3), "….3")
+#test(numbering("1.a.i", max-depth: 3, ellipsis: "~", 1, 2, 3, 4), "1.b.~.iv")
+#test(numbering("(1.a)", max-depth: 2, trim: true, 1, 2, 3), "1.….c")
+#test(numbering("(1.a)", max-depth: 1, trim: true, 1, 2, 3), "….c")
+
+--- numbering-parse ---
+#test(numbering.parse("I.1", "IV.2"), (4, 2))
+#test(numbering.parse("A.1)", "B.3)"), (2, 3))
+#test(numbering.parse("A.1)", "B)"), (2,))
+#test(numbering.parse("1.1", "1.2.3"), (1, 2, 3))
+#test(numbering.parse("001", "007"), (7,))
+#test(numbering.parse("α", "σμαʹ"), (241,))
+#test(numbering.parse("א", "ה׳תשפ״ד"), (5784,))
+#test(numbering.parse("א", numbering("א", 1000)), (1000,))
+#test(numbering.parse("א", numbering("א", 1)), (1,))
+#test(numbering.parse("1", "IV"), none)
+#test(numbering.parse("i", "iiii"), none)
+#test(numbering.parse("11", "123"), none)
+#test(numbering.parse("a.1", numbering("a.1", 27, 3)), (27, 3))
+#test(numbering.parse("1", "1" * 200 + "x"), none)
+
+--- numbering-serialize ---
+#set heading(numbering: "(1.a)")
+#show heading: none
+= Intro
+#context {
+  let numbering = query(heading).first().numbering
+  test(json.encode(numbering, pretty: false), "\"(1.a)\"")
+  test(json(bytes(json.encode(numbering))), "(1.a)")
+}
+
 --- numbering-negative ---
-// Error: 17-19 number must be at least zero
-#numbering("1", -1)
+#test(numbering("1", -1), "\u{2212}1")
+#test(numbering("1", 0), "0")
+#test(numbering("1.1", 2, -3), "2.\u{2212}3")
+#test(numbering("(001)", -7), "(\u{2212}007)")
+#test(numbering("I", -4), "\u{2212}IV")
+#test(numbering("a", -1), "\u{2212}a")
+#test(numbering("*", -2), "\u{2212}†")
+#test(numbering("\u{0661}", -12), "\u{061C}-\u{0661}\u{0662}")
+#test(numbering("1", -9223372036854775807 - 1), "\u{2212}9223372036854775808")